@@ -0,0 +1,209 @@
+//! Derive macros for the [`matrixable`](https://docs.rs/matrixable) traits `MatrixExt` and `MatrixMutExt`.
+//!
+//! They are re-exported by `matrixable` when its **derive** feature is enabled.
+//!
+//! Two layouts are supported:
+//! * A struct with a single field which already implements the trait (a 2D array for example).
+//!   All calls are forwarded to that field.
+//! * A struct storing its elements in *Row Major Order* in a field marked `#[matrix(data)]`
+//!   (a `Vec<T>`, a boxed slice, an array...) and its number of columns in a `usize` field marked `#[matrix(cols)]`.
+//!
+//! # Example
+//! ```rust
+//! use matrixable::{ MatrixExt, MatrixMutExt };
+//!
+//! #[derive(MatrixExt, MatrixMutExt)]
+//! struct Board([[char; 3]; 3]);
+//!
+//! #[derive(MatrixExt, MatrixMutExt)]
+//! struct Image {
+//!     #[matrix(data)]
+//!     pixels: Vec<u8>,
+//!     #[matrix(cols)]
+//!     width: usize,
+//! }
+//!
+//! let mut board = Board([['.'; 3]; 3]);
+//! board.set((1, 1), 'x').unwrap();
+//! assert_eq!(Some(&'x'), board.get(1, 1));
+//!
+//! let mut image = Image { pixels: vec![0, 1, 2, 3, 4, 5], width: 3 };
+//! *image.get_mut(1, 0).unwrap() = 30;
+//! assert_eq!((2, 3), image.shape());
+//! assert_eq!(vec![vec![0, 1, 2], vec![30, 4, 5]], image.to_vecs());
+//! assert_eq!(None, image.get(0, 3));
+//!
+//! #[derive(MatrixExt, MatrixMutExt)]
+//! struct Frame {
+//!     #[matrix(data)]
+//!     pixels: Box<[u8]>,
+//!     #[matrix(cols)]
+//!     width: usize,
+//! }
+//!
+//! let mut frame = Frame { pixels: vec![0; 6].into_boxed_slice(), width: 2 };
+//! frame.set((2, 1), 7).unwrap();
+//! assert_eq!((3, 2), frame.shape());
+//! assert_eq!(Some(&7), frame.get(2, 1));
+//! ```
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{ parse_macro_input, Data, DeriveInput, Fields, Index, Member, Type };
+
+
+/// How the elements of the matrix are stored in the struct.
+enum Layout {
+    /// A single field implementing the trait.
+    Delegate { field: Member, ty: Type },
+    /// Elements in Row Major Order and a number of columns.
+    Flat { data: Member, data_ty: Type, cols: Member },
+}
+
+fn layout(input: &DeriveInput) -> syn::Result<Layout> {
+    let fields = match &input.data {
+        Data::Struct(s) => &s.fields,
+        _ => return Err(syn::Error::new_spanned(&input.ident, "matrix traits can only be derived for structs")),
+    };
+    let fields: Vec<_> = match fields {
+        Fields::Named(f) => f.named.iter().collect(),
+        Fields::Unnamed(f) => f.unnamed.iter().collect(),
+        Fields::Unit => Vec::new(),
+    };
+
+    let mut data = None;
+    let mut cols = None;
+    for (n, field) in fields.iter().enumerate() {
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(n)),
+        };
+        for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("matrix")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("data") {
+                    data = Some((member.clone(), field.ty.clone()));
+                    Ok(())
+                }
+                else if meta.path.is_ident("cols") {
+                    cols = Some(member.clone());
+                    Ok(())
+                }
+                else {
+                    Err(meta.error("expected `data` or `cols`"))
+                }
+            })?;
+        }
+    }
+
+    match (data, cols) {
+        (Some((data, data_ty)), Some(cols)) => Ok(Layout::Flat { data, data_ty, cols }),
+        (None, None) if fields.len() == 1 => Ok(Layout::Delegate {
+            field: match &fields[0].ident {
+                Some(ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(Index::from(0)),
+            },
+            ty: fields[0].ty.clone(),
+        }),
+        _ => Err(syn::Error::new_spanned(
+            &input.ident,
+            "expected a single field, or fields marked `#[matrix(data)]` and `#[matrix(cols)]`"
+        )),
+    }
+}
+
+/// Derives `matrixable::MatrixExt`. See the [crate documentation](crate) for supported layouts.
+#[proc_macro_derive(MatrixExt, attributes(matrix))]
+pub fn derive_matrix_ext(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_matrix_ext(&input).unwrap_or_else(|e| e.to_compile_error()).into()
+}
+
+/// Derives `matrixable::MatrixMutExt`. See the [crate documentation](crate) for supported layouts.
+#[proc_macro_derive(MatrixMutExt, attributes(matrix))]
+pub fn derive_matrix_mut_ext(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_matrix_mut_ext(&input).unwrap_or_else(|e| e.to_compile_error()).into()
+}
+
+fn expand_matrix_ext(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let body = match layout(input)? {
+        Layout::Delegate { field, ty } => quote! {
+            type Element = <#ty as ::matrixable::MatrixExt>::Element;
+
+            #[inline]
+            fn num_rows(&self) -> usize { ::matrixable::MatrixExt::num_rows(&self.#field) }
+
+            #[inline]
+            fn num_cols(&self) -> usize { ::matrixable::MatrixExt::num_cols(&self.#field) }
+
+            #[inline]
+            fn get(&self, row: usize, column: usize) -> ::core::option::Option<&Self::Element> {
+                ::matrixable::MatrixExt::get(&self.#field, row, column)
+            }
+        },
+        Layout::Flat { data, data_ty, cols } => {
+            // Arrays are indexed directly, other containers (`Vec<T>`, `Box<[T]>`...) through the slice they deref to.
+            let element = match &data_ty {
+                Type::Array(_) => quote! { <#data_ty as ::core::ops::Index<usize>>::Output },
+                _ => quote! { <<#data_ty as ::core::ops::Deref>::Target as ::core::ops::Index<usize>>::Output },
+            };
+            quote! {
+                type Element = #element;
+
+                #[inline]
+                fn num_rows(&self) -> usize { self.#data.len().checked_div(self.#cols).unwrap_or(0) }
+
+                #[inline]
+                fn num_cols(&self) -> usize { self.#cols }
+
+                #[inline]
+                fn get(&self, row: usize, column: usize) -> ::core::option::Option<&Self::Element> {
+                    if row >= ::matrixable::MatrixExt::num_rows(self) || column >= self.#cols {
+                        return ::core::option::Option::None
+                    }
+                    self.#data.get(row * self.#cols + column)
+                }
+            }
+        },
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::matrixable::MatrixExt for #name #ty_generics #where_clause {
+            #body
+        }
+    })
+}
+
+fn expand_matrix_mut_ext(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let body = match layout(input)? {
+        Layout::Delegate { field, .. } => quote! {
+            #[inline]
+            fn get_mut(&mut self, row: usize, column: usize) -> ::core::option::Option<&mut Self::Element> {
+                ::matrixable::MatrixMutExt::get_mut(&mut self.#field, row, column)
+            }
+        },
+        Layout::Flat { data, cols, .. } => quote! {
+            #[inline]
+            fn get_mut(&mut self, row: usize, column: usize) -> ::core::option::Option<&mut Self::Element> {
+                if row >= ::matrixable::MatrixExt::num_rows(self) || column >= self.#cols {
+                    return ::core::option::Option::None
+                }
+                let n = row * self.#cols + column;
+                self.#data.get_mut(n)
+            }
+        },
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::matrixable::MatrixMutExt for #name #ty_generics #where_clause {
+            #body
+        }
+    })
+}
@@ -4,6 +4,10 @@ use crate::strategies::*;
 
 use crate::{ MatrixExt, MatrixMutExt };
 use crate::req::MatrixExtFromIter;
+use crate::wrappers::MatrixDebug;
+
+#[cfg(feature = "serde")]
+use serde::{ Serialize, Deserialize };
 
 
 /// A `MatrixExt` which provides immutable access to another matrix by following a certain access strategy.
@@ -26,6 +30,18 @@ pub struct AccessMut<'a, M: MatrixExt, S: AccessStrategy<M>>{
     pub strategy: S,
 }
 
+/// A `MatrixMutExt` which owns another matrix and provides access to it by following a certain access strategy.
+///
+/// Unlike [`Access`] and [`AccessMut`], it does not borrow the matrix, so it can be returned from functions.
+/// 
+/// This `struct` is created by the [`into_access`](crate::MatrixExt::into_access) method on `MatrixExt`. See its documentation for more.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Hash, Clone, Debug)]
+pub struct AccessOwned<M: MatrixExt, S: AccessStrategy<M>>{
+    matrix: M,
+    pub strategy: S,
+}
+
 /// Used by [`AccessStrategySet`].
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Default, Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
@@ -45,6 +61,26 @@ impl<'a, M: MatrixExt, S: AccessStrategy<M>> Access<'a, M, S> {
     where M: for<'b> MatrixExtFromIter<&'b M::Element> {
         MatrixExtFromIter::from_iter(self.iter(), self.num_cols())
     }
+
+    /// Returns an object whose `Debug` implementation prints the rows of the matrix as seen through the strategy,
+    /// instead of the underlying matrix and the strategy (which is what `Access` own `Debug` implementation does).
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    /// use matrixable::strategies::{ AccessStrategySet, Transpose, FlipH };
+    ///
+    /// let m = [[1, 2, 3], [4, 5, 6]];
+    ///
+    /// let strategy: AccessStrategySet = vec![Box::new(Transpose), Box::new(FlipH)];
+    /// let access = m.access(strategy);
+    ///
+    /// assert_eq!("[[4, 1], [5, 2], [6, 3]]", format!("{:?}", access.debug_resolved()));
+    /// ```
+    #[inline]
+    pub fn debug_resolved(&self) -> MatrixDebug<'_, Self> {
+        MatrixDebug::new(self)
+    }
 }
 
 impl<'a, M: MatrixMutExt, S: AccessStrategy<M>> AccessMut<'a, M, S> {
@@ -59,6 +95,43 @@ impl<'a, M: MatrixMutExt, S: AccessStrategy<M>> AccessMut<'a, M, S> {
     {
         self.matrix.clone()
     }
+
+    /// Same as [`Access::debug_resolved`].
+    #[inline]
+    pub fn debug_resolved(&self) -> MatrixDebug<'_, Self> {
+        MatrixDebug::new(self)
+    }
+}
+
+impl<M: MatrixExt, S: AccessStrategy<M>> AccessOwned<M, S> {
+    pub(crate) fn new(matrix: M, strategy: S) -> Self {
+        Self { matrix, strategy }
+    }
+
+    /// Returns a reference to the underlying matrix.
+    #[inline]
+    pub fn inner(&self) -> &M { &self.matrix }
+
+    /// Returns the underlying matrix, dropping the strategy.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    /// use matrixable::strategies::Transpose;
+    ///
+    /// let access = [[1, 2, 3], [4, 5, 6]].into_access(Transpose);
+    ///
+    /// assert_eq!((3, 2), access.shape());
+    /// assert_eq!([[1, 2, 3], [4, 5, 6]], access.into_inner());
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> M { self.matrix }
+
+    /// Same as [`Access::debug_resolved`].
+    #[inline]
+    pub fn debug_resolved(&self) -> MatrixDebug<'_, Self> {
+        MatrixDebug::new(self)
+    }
 }
 
 impl Observer {
@@ -72,8 +145,7 @@ impl Observer {
 
     #[inline]
     pub fn update_dimensions(&mut self, s: &dyn AccessStrategy<Self>) {
-        self.rows = s.nrows(self);
-        self.cols = s.ncols(self);
+        (self.rows, self.cols) = (s.nrows(self), s.ncols(self));
     }
 }
 
@@ -81,6 +153,8 @@ impl Observer {
 
 // ### MatrixExt Implementation
 
+const NO_ELEMENT: &str = "The access strategy gives no element for these subscripts.";
+
 impl MatrixExt for Observer {
     type Element = ();
 
@@ -106,6 +180,20 @@ impl<'a, M: MatrixExt, S: AccessStrategy<M>> MatrixExt for Access<'a, M, S> {
         let (i, j) = self.strategy.access(self.matrix, row, column)?;
         self.matrix.get(i, j)
     }
+
+    /// Skips only the bound checking on the accessed matrix when the strategy implements [`TrustedAccess`]:
+    /// the strategy still maps (and checks) the subscripts.
+    ///
+    /// # Panics
+    /// Panics if the strategy gives no element for these subscripts.
+    #[inline]
+    unsafe fn get_unchecked(&self, row: usize, column: usize) -> &Self::Element {
+        let (i, j) = self.strategy.access(self.matrix, row, column).expect(NO_ELEMENT);
+        match self.strategy.trusted() {
+            Some(_) => self.matrix.get_unchecked(i, j),
+            None => self.matrix.get(i, j).expect(NO_ELEMENT),
+        }
+    }
 }
 impl<'a, M: MatrixMutExt, S: AccessStrategy<M>> MatrixExt for AccessMut<'a, M, S> {
     type Element = M::Element;
@@ -118,6 +206,46 @@ impl<'a, M: MatrixMutExt, S: AccessStrategy<M>> MatrixExt for AccessMut<'a, M, S
         let (i, j) = self.strategy.access(self.matrix, row, column)?;
         self.matrix.get(i, j) 
     }
+
+    /// Skips only the bound checking on the accessed matrix when the strategy implements [`TrustedAccess`]:
+    /// the strategy still maps (and checks) the subscripts.
+    ///
+    /// # Panics
+    /// Panics if the strategy gives no element for these subscripts.
+    #[inline]
+    unsafe fn get_unchecked(&self, row: usize, column: usize) -> &Self::Element {
+        let (i, j) = self.strategy.access(self.matrix, row, column).expect(NO_ELEMENT);
+        match self.strategy.trusted() {
+            Some(_) => self.matrix.get_unchecked(i, j),
+            None => self.matrix.get(i, j).expect(NO_ELEMENT),
+        }
+    }
+}
+impl<M: MatrixExt, S: AccessStrategy<M>> MatrixExt for AccessOwned<M, S> {
+    type Element = M::Element;
+
+    #[inline] fn num_rows(&self) -> usize { self.strategy.nrows(&self.matrix) }
+    #[inline] fn num_cols(&self) -> usize { self.strategy.ncols(&self.matrix) }
+
+    #[inline]
+    fn get(&self, row: usize, column: usize) -> Option<&Self::Element> { 
+        let (i, j) = self.strategy.access(&self.matrix, row, column)?;
+        self.matrix.get(i, j) 
+    }
+
+    /// Skips only the bound checking on the accessed matrix when the strategy implements [`TrustedAccess`]:
+    /// the strategy still maps (and checks) the subscripts.
+    ///
+    /// # Panics
+    /// Panics if the strategy gives no element for these subscripts.
+    #[inline]
+    unsafe fn get_unchecked(&self, row: usize, column: usize) -> &Self::Element {
+        let (i, j) = self.strategy.access(&self.matrix, row, column).expect(NO_ELEMENT);
+        match self.strategy.trusted() {
+            Some(_) => self.matrix.get_unchecked(i, j),
+            None => self.matrix.get(i, j).expect(NO_ELEMENT),
+        }
+    }
 }
 impl<'a, M: MatrixMutExt, S: AccessStrategy<M>> MatrixMutExt for AccessMut<'a, M, S> {
     #[inline]
@@ -125,5 +253,39 @@ impl<'a, M: MatrixMutExt, S: AccessStrategy<M>> MatrixMutExt for AccessMut<'a, M
         let (i, j) = self.strategy.access(self.matrix, row, column)?;
         self.matrix.get_mut(i, j) 
     }
+
+    /// Skips only the bound checking on the accessed matrix when the strategy implements [`TrustedAccess`]:
+    /// the strategy still maps (and checks) the subscripts.
+    ///
+    /// # Panics
+    /// Panics if the strategy gives no element for these subscripts.
+    #[inline]
+    unsafe fn get_unchecked_mut(&mut self, row: usize, column: usize) -> &mut Self::Element {
+        let (i, j) = self.strategy.access(self.matrix, row, column).expect(NO_ELEMENT);
+        match self.strategy.trusted() {
+            Some(_) => self.matrix.get_unchecked_mut(i, j),
+            None => self.matrix.get_mut(i, j).expect(NO_ELEMENT),
+        }
+    }
 }
+impl<M: MatrixMutExt, S: AccessStrategy<M>> MatrixMutExt for AccessOwned<M, S> {
+    #[inline]
+    fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut Self::Element> { 
+        let (i, j) = self.strategy.access(&self.matrix, row, column)?;
+        self.matrix.get_mut(i, j) 
+    }
 
+    /// Skips only the bound checking on the accessed matrix when the strategy implements [`TrustedAccess`]:
+    /// the strategy still maps (and checks) the subscripts.
+    ///
+    /// # Panics
+    /// Panics if the strategy gives no element for these subscripts.
+    #[inline]
+    unsafe fn get_unchecked_mut(&mut self, row: usize, column: usize) -> &mut Self::Element {
+        let (i, j) = self.strategy.access(&self.matrix, row, column).expect(NO_ELEMENT);
+        match self.strategy.trusted() {
+            Some(_) => self.matrix.get_unchecked_mut(i, j),
+            None => self.matrix.get_mut(i, j).expect(NO_ELEMENT),
+        }
+    }
+}
@@ -0,0 +1,191 @@
+//! Cursors walking through a matrix one step at a time.
+//!
+//! # Example
+//! ```rust
+//! use matrixable::MatrixExt;
+//!
+//! let maze = [
+//!     ['#', '#', '#', '#'],
+//!     ['#', '.', '.', '#'],
+//!     ['#', '#', '.', '#'],
+//!     ['#', '#', '#', '#'],
+//! ];
+//!
+//! let mut cursor = maze.cursor(1, 1).unwrap();
+//!
+//! assert!(cursor.right());
+//! assert_eq!(Some(&'#'), cursor.peek(0, 1));
+//! assert!(cursor.down());
+//! assert_eq!((2, 2), cursor.position());
+//! assert_eq!(&'.', cursor.current());
+//! ```
+
+use crate::{ MatrixExt, MatrixMutExt };
+
+
+/// Position reached after moving by `(di, dj)` from `(i, j)` inside a matrix of the given shape.
+fn target(
+    (i, j): (usize, usize),
+    (rows, cols): (usize, usize),
+    (di, dj): (isize, isize),
+    wrap: bool
+) -> Option<(usize, usize)> {
+    if wrap {
+        let i = (i as isize).checked_add(di)?.rem_euclid(rows as isize) as usize;
+        let j = (j as isize).checked_add(dj)?.rem_euclid(cols as isize) as usize;
+        Some((i, j))
+    }
+    else {
+        let i = i.checked_add_signed(di).filter(|&i| i < rows)?;
+        let j = j.checked_add_signed(dj).filter(|&j| j < cols)?;
+        Some((i, j))
+    }
+}
+
+macro_rules! moves {
+    () => {
+        /// Makes the cursor wrap around the edges of the matrix: moving past an edge
+        /// brings it back to the opposite edge.
+        #[inline]
+        pub fn wrapping(mut self) -> Self {
+            self.wrap = true;
+            self
+        }
+
+        /// Returns the subscripts of the current element.
+        #[inline]
+        pub fn position(&self) -> (usize, usize) { self.pos }
+
+        /// Moves the cursor by `di` rows and `dj` columns.
+        ///
+        /// Returns `false` and does not move if the new position is out of bounds (only possible without wrapping).
+        pub fn move_by(&mut self, di: isize, dj: isize) -> bool {
+            match target(self.pos, self.matrix.shape(), (di, dj), self.wrap) {
+                Some(pos) => {
+                    self.pos = pos;
+                    true
+                }
+                None => false,
+            }
+        }
+
+        /// Moves the cursor one row up. See [`move_by`](#method.move_by).
+        #[inline]
+        pub fn up(&mut self) -> bool { self.move_by(-1, 0) }
+
+        /// Moves the cursor one row down. See [`move_by`](#method.move_by).
+        #[inline]
+        pub fn down(&mut self) -> bool { self.move_by(1, 0) }
+
+        /// Moves the cursor one column left. See [`move_by`](#method.move_by).
+        #[inline]
+        pub fn left(&mut self) -> bool { self.move_by(0, -1) }
+
+        /// Moves the cursor one column right. See [`move_by`](#method.move_by).
+        #[inline]
+        pub fn right(&mut self) -> bool { self.move_by(0, 1) }
+    };
+}
+
+/// A cursor pointing to an element of a matrix, that can be moved around it.
+///
+/// This struct is created by the [`cursor`](crate::MatrixExt::cursor) method on [`MatrixExt`].
+#[derive(Debug)]
+pub struct Cursor<'a, M: MatrixExt> {
+    matrix: &'a M,
+    pos: (usize, usize),
+    wrap: bool,
+}
+
+impl<'a, M: MatrixExt> Clone for Cursor<'a, M> {
+    #[inline]
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, M: MatrixExt> Copy for Cursor<'a, M> {}
+
+impl<'a, M: MatrixExt> Cursor<'a, M> {
+    pub(crate) fn new(matrix: &'a M, i: usize, j: usize) -> Option<Self> {
+        if !matrix.check(i, j) {
+            return None
+        }
+        Some(Self { matrix, pos: (i, j), wrap: false })
+    }
+
+    moves!();
+
+    /// Returns the current element.
+    #[inline]
+    pub fn current(&self) -> &'a M::Element {
+        self.matrix.get(self.pos.0, self.pos.1).unwrap()
+    }
+
+    /// Returns the element `di` rows and `dj` columns away from the current one, without moving the cursor.
+    #[inline]
+    pub fn peek(&self, di: isize, dj: isize) -> Option<&'a M::Element> {
+        let (i, j) = target(self.pos, self.matrix.shape(), (di, dj), self.wrap)?;
+        self.matrix.get(i, j)
+    }
+}
+
+/// A cursor pointing to an element of a matrix, that can be moved around it and modify elements.
+///
+/// This struct is created by the [`cursor_mut`](crate::MatrixMutExt::cursor_mut) method on [`MatrixMutExt`].
+///
+/// # Example
+/// ```rust
+/// use matrixable::MatrixMutExt;
+///
+/// let mut m = [[0; 3]; 2];
+/// let mut cursor = m.cursor_mut(0, 0).unwrap().wrapping();
+///
+/// for k in 1..=3 {
+///     *cursor.current_mut() = k;
+///     cursor.left();
+/// }
+///
+/// assert_eq!([[1, 3, 2], [0, 0, 0]], m);
+/// ```
+#[derive(Debug)]
+pub struct CursorMut<'a, M: MatrixMutExt> {
+    matrix: &'a mut M,
+    pos: (usize, usize),
+    wrap: bool,
+}
+
+impl<'a, M: MatrixMutExt> CursorMut<'a, M> {
+    pub(crate) fn new(matrix: &'a mut M, i: usize, j: usize) -> Option<Self> {
+        if !matrix.check(i, j) {
+            return None
+        }
+        Some(Self { matrix, pos: (i, j), wrap: false })
+    }
+
+    moves!();
+
+    /// Returns the current element.
+    #[inline]
+    pub fn current(&self) -> &M::Element {
+        self.matrix.get(self.pos.0, self.pos.1).unwrap()
+    }
+
+    /// Returns the current element (mutable).
+    #[inline]
+    pub fn current_mut(&mut self) -> &mut M::Element {
+        self.matrix.get_mut(self.pos.0, self.pos.1).unwrap()
+    }
+
+    /// Returns the element `di` rows and `dj` columns away from the current one, without moving the cursor.
+    #[inline]
+    pub fn peek(&self, di: isize, dj: isize) -> Option<&M::Element> {
+        let (i, j) = target(self.pos, self.matrix.shape(), (di, dj), self.wrap)?;
+        self.matrix.get(i, j)
+    }
+
+    /// Returns the element `di` rows and `dj` columns away from the current one (mutable), without moving the cursor.
+    #[inline]
+    pub fn peek_mut(&mut self, di: isize, dj: isize) -> Option<&mut M::Element> {
+        let (i, j) = target(self.pos, self.matrix.shape(), (di, dj), self.wrap)?;
+        self.matrix.get_mut(i, j)
+    }
+}
@@ -10,7 +10,7 @@ impl<T, const N: usize, const M: usize> MatrixExt for [[T; N]; M]
     
     fn num_rows(&self) -> usize { 
         let len = self.len();
-        if len != 0 && self[0].len() != 0 {
+        if len != 0 && !self[0].is_empty() {
             len
         } 
         else {
@@ -18,7 +18,7 @@ impl<T, const N: usize, const M: usize> MatrixExt for [[T; N]; M]
         }
     }
     fn num_cols(&self) -> usize { 
-        if self.len() == 0 { 
+        if self.as_slice().is_empty() { 
             0 
         } else {
             self[0].len() 
@@ -27,12 +27,115 @@ impl<T, const N: usize, const M: usize> MatrixExt for [[T; N]; M]
     fn get(&self, i: usize, j: usize) -> Option<&Self::Element> {
         self.as_slice().get(i)?.as_slice().get(j)
     }
+
+    // Elements are contiguous in Row Major Order, so linear accesses (used by iterators)
+    // do not need to go through subscripts.
+    #[inline]
+    fn get_nth(&self, n: usize) -> Option<&Self::Element> {
+        self.as_flattened().get(n)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, i: usize, j: usize) -> &Self::Element {
+        self.as_slice().get_unchecked(i).as_slice().get_unchecked(j)
+    }
+
+    #[inline]
+    unsafe fn get_nth_unchecked(&self, n: usize) -> &Self::Element {
+        self.as_flattened().get_unchecked(n)
+    }
 }
 
 impl<T, const N: usize, const M: usize> MatrixMutExt for [[T; N]; M] {
     fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut Self::Element> {
         self.as_mut_slice().get_mut(i)?.as_mut_slice().get_mut(j)
     }
+
+    #[inline]
+    fn get_nth_mut(&mut self, n: usize) -> Option<&mut Self::Element> {
+        self.as_flattened_mut().get_mut(n)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked_mut(&mut self, i: usize, j: usize) -> &mut Self::Element {
+        self.as_mut_slice().get_unchecked_mut(i).as_mut_slice().get_unchecked_mut(j)
+    }
+
+    #[inline]
+    unsafe fn get_nth_unchecked_mut(&mut self, n: usize) -> &mut Self::Element {
+        self.as_flattened_mut().get_unchecked_mut(n)
+    }
+
+    // Rows are arrays, so they are swapped as a whole.
+    #[inline]
+    fn swap_rows(&mut self, row1: usize, row2: usize) {
+        self.as_mut_slice().swap(row1, row2)
+    }
+
+    #[inline]
+    fn swap_cols(&mut self, col1: usize, col2: usize) {
+        for row in self.as_mut_slice() {
+            row.swap(col1, col2)
+        }
+    }
+
+    #[inline]
+    fn apply(&mut self, f: impl FnMut(&mut Self::Element)) {
+        self.as_flattened_mut().iter_mut().for_each(f)
+    }
+
+    #[inline]
+    fn apply_indexed(&mut self, mut f: impl FnMut(usize, usize, &mut Self::Element)) {
+        for (i, row) in self.as_mut_slice().iter_mut().enumerate() {
+            for (j, x) in row.iter_mut().enumerate() {
+                f(i, j, x)
+            }
+        }
+    }
+}
+
+impl<T, const N: usize, const M: usize> MatrixSlices for [[T; N]; M] {
+    #[inline]
+    fn as_flat_slice(&self) -> &[Self::Element] {
+        self.as_flattened()
+    }
+}
+
+impl<T, const N: usize, const M: usize> MatrixSlicesMut for [[T; N]; M] {
+    #[inline]
+    fn as_flat_slice_mut(&mut self) -> &mut [Self::Element] {
+        self.as_flattened_mut()
+    }
+}
+
+impl<T, const N: usize, const M: usize> MatrixExtFromIter<T> for [[T; N]; M] {
+    /// # Panics
+    /// Panics if the iterator does not contain exactly `M * N` elements or if `columns` is not equal to `N`.
+    fn from_iter<I>(into_iter: I, columns: usize) -> Self
+        where I: IntoIterator<Item = T>,
+             <I as IntoIterator>::IntoIter: ExactSizeIterator
+    {
+        let mut iter = into_iter.into_iter();
+        if iter.len() != M * N || (M * N != 0 && columns != N) {
+            panic!("Cannot build a {:?} array from {} elements on {} columns.", (M, N), iter.len(), columns)
+        }
+        ::core::array::from_fn(|_| 
+            ::core::array::from_fn(|_| 
+                iter.next().unwrap()
+            )
+        )
+    }
+
+    fn try_from_iter<I>(into_iter: I, columns: usize) -> Result<Self, &'static str>
+        where I: IntoIterator<Item = T>,
+             <I as IntoIterator>::IntoIter: ExactSizeIterator
+    {
+        let iter = into_iter.into_iter();
+        if iter.len() != M * N || (M * N != 0 && columns != N) {
+            return Err("Cannot build an array of this shape from these elements.")
+        }
+        Ok(Self::from_iter(iter, columns))
+    }
 }
 
 impl<T: Default, const M: usize, const N: usize> TransformStrategy<[[T; M]; N]> for Transpose {
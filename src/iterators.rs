@@ -1,6 +1,7 @@
 //! This module contains structs for iterating over matrices.
 //!
 //! Some of these structs also implement [`Index`](::core::ops::Index) allowing you to use the `container[index]` notation.
+//! [`Rows`], [`Columns`], [`Diags`] and [`AntiDiags`] provide a `get` method instead, since they create the iterators they yield.
 //!
 //! # Examples
 //! ```rust
@@ -39,6 +40,21 @@
 //! assert_eq!('r', it[0]);
 //! assert_eq!('i', it[1]);
 //! assert_eq!('o', it[2]); 
+//!
+//! let m = [
+//!     [1, 2, 3],
+//!     [4, 5, 6],
+//!     [7, 8, 9]
+//! ];
+//! let mut rows = m.rows();
+//!
+//! assert_eq!(8, rows.get(2).unwrap()[1]);
+//! rows.next();
+//! assert_eq!(Some(7), rows.get(1).map(|row| row[0]));
+//! assert!(rows.get(2).is_none());
+//!
+//! assert_eq!(vec![&3, &6, &9], m.cols().get(2).unwrap().collect::<Vec<_>>());
+//! assert_eq!(vec![&1, &5, &9], m.diags().get(2).unwrap().collect::<Vec<_>>());
 //! ```
 //! <br/>
 //!
@@ -69,7 +85,7 @@ use ::core::{
     fmt::Debug,
     iter::{ FusedIterator, FromIterator },
     marker::PhantomData,
-    
+    ops::{ Range, RangeFull },
 };
 
 
@@ -78,6 +94,9 @@ use alloc::vec::IntoIter;
 use alloc::boxed::Box;
 
 use crate::{MatrixExt, MatrixMutExt};
+use crate::access::Access;
+use crate::req::{ MatrixSlices, MatrixSlicesMut };
+use crate::strategies::Submatrix;
 
 macro_rules! iter {
     (
@@ -261,17 +280,28 @@ iter!{
     "An iterator over the elements of the matrix (mutable).\n\n\
     This struct is created by the [`iter_mut`](MatrixMutExt::iter_mut) method on [`MatrixMutExt`]."]
     IterMut { mut } { mut } MatrixMutExt get_nth_mut;
-    |m: &M| (0, m.size().saturating_sub(1)) ;
+    |m: &M| match m.size() {
+        0 => (1, 0),
+        size => (0, size - 1),
+    } ;
     fn increment(&self, i: usize) -> usize {
         i + 1
     } ;
-    fn len(&self) -> usize { self.matrix().size()  } ;
+    fn len(&self) -> usize {
+        if self.i > self.irev {
+            return 0
+        }
+        self.irev - self.i + 1
+    } ;
     fn next_back(&mut self) -> Option<Self::Item> {
         if self.i > self.irev {
             return None
         }
         let j = self.irev;
-        self.irev -= 1;
+        match j.checked_sub(1) {
+            Some(k) => self.irev = k,
+            None => self.i = j + 1,
+        }
         
         // SAFETY: Nothing else points to or will point to the contents of this iterator.
         self.get_nth(j)
@@ -289,25 +319,70 @@ iter!{
     RowMut { mut } { mut } MatrixMutExt get_nth_mut irow;
     |m: &M, row| {
         let rlen = m.row_len();
+        if rlen == 0 {
+            return (1, 0)
+        }
         let i = row * rlen;
         (i, i + rlen - 1)
     } ;
     fn increment(&self, i: usize) -> usize {  
         i + 1
     } ;
-    fn len(&self) -> usize {  self.matrix().row_len()  } ;
+    fn len(&self) -> usize {
+        if self.i > self.irev {
+            return 0
+        }
+        self.irev - self.i + 1
+    } ;
     fn next_back(&mut self) -> Option<Self::Item> {
         if self.i > self.irev {
             return None
         }
         let j = self.irev;
-        self.irev -= 1;
+        match j.checked_sub(1) {
+            Some(k) => self.irev = k,
+            None => self.i = j + 1,
+        }
         
         // SAFETY: Nothing else points to or will point to the contents of this iterator.
         self.get_nth(j)
     }
 }
 
+macro_rules! as_slice {
+    ($($name:ident)*) => {$(
+        impl<'a, M: MatrixSlices> $name<'a, M> {
+            /// Returns the remaining elements of the iterator as a slice, like
+            /// [`slice::Iter::as_slice`](::core::slice::Iter::as_slice).
+            ///
+            /// # Example
+            /// ```rust
+            /// use matrixable::MatrixExt;
+            ///
+            /// let m = [[1, 2, 3], [4, 5, 6]];
+            ///
+            /// let mut iter = m.iter();
+            /// iter.next();
+            /// iter.next_back();
+            /// assert_eq!(&[2, 3, 4, 5], iter.as_slice());
+            ///
+            /// let mut row = m.row(1).unwrap();
+            /// row.next();
+            /// assert_eq!(&[5, 6], row.as_slice());
+            /// ```
+            #[inline]
+            pub fn as_slice(&self) -> &'a [M::Element] {
+                if self.i > self.irev {
+                    return &[]
+                }
+                self.matrix().as_flat_slice().get(self.i..=self.irev).unwrap_or(&[])
+            }
+        }
+    )*};
+}
+
+as_slice! { Iter Row }
+
 macro_rules! dimensional_iterator {
     (   $w:ident,
         $ptr:ident,
@@ -448,18 +523,29 @@ iter!{
     ColumnMut { mut } { mut} MatrixMutExt get_nth_mut icol;
     |m: &M, col| {
         let (rows, cols) = m.shape();
+        if rows == 0 {
+            return (1, 0)
+        }
         (col, (rows * cols) - cols.saturating_sub(col))
     } ;
     fn increment(&self, i: usize) -> usize {
         i + self.matrix().row_len()
     } ;
-    fn len(&self) -> usize { self.matrix().col_len()  } ;
+    fn len(&self) -> usize {
+        if self.i > self.irev {
+            return 0
+        }
+        (self.irev - self.i) / self.matrix().row_len() + 1
+    } ;
     fn next_back(&mut self) -> Option<Self::Item> {
         if self.i > self.irev {
             return None
         }
         let j = self.irev;
-        self.irev -= self.use_matrix().row_len();
+        match j.checked_sub(self.use_matrix().row_len()) {
+            Some(k) => self.irev = k,
+            None => self.i = j + 1,
+        }
 
         // SAFETY: Nothing else points to or will point to the contents of this iterator.
         self.get_nth(j)
@@ -475,26 +561,19 @@ iter!{
     "An iterator over a mutable matrix diagonal.\n\n\
     This struct is created by the [`diag_mut`](MatrixMutExt::diag_mut) method on [`MatrixMutExt`]."]
     DiagMut { mut } { mut } MatrixMutExt get_nth_mut n;
-    |m: &M, mut n| {
+    |m: &M, n| {
         let (rows, cols) = match m.shape() {
-            (_, 0) | (0, _) => return (0, 1),
+            (_, 0) | (0, _) => return (1, 0),
             (rows, cols) => (rows, cols)
         } ;
         let diag_len = m.diag_len(n);
         let main_diag = rows - 1;
-        if n < main_diag {
-            n = main_diag - n;
-            (
-                n * cols,
-                n + cols * (diag_len + 1),
-            )
+        let start = if n < main_diag {
+            (main_diag - n) * cols
         } else {
-            n = n - main_diag;
-            (
-                n,
-                n + cols * (diag_len + 1),
-            )
-        }
+            n - main_diag
+        };
+        (start, start + (diag_len - 1) * (cols + 1))
     } ;
     fn increment(&self, i: usize) -> usize {
         let m = self.matrix();
@@ -511,19 +590,149 @@ iter!{
             self.irev + 1
         }
     };
-    fn len(&self) -> usize {  self.matrix().diag_len(self.i) };
+    fn len(&self) -> usize {
+        if self.i > self.irev {
+            return 0
+        }
+        (self.irev - self.i) / (self.matrix().row_len() + 1) + 1
+    } ;
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.i > self.irev {
+            return None
+        }
+        let j = self.irev;
+        match j.checked_sub(self.use_matrix().row_len() + 1) {
+            Some(k) => self.irev = k,
+            None => self.i = j + 1,
+        }
+
+        // SAFETY: Nothing else points to or will point to the contents of this iterator.
+        self.get_nth(j)
+    }
+}
+
+
+iter!{
+    #[doc =
+    "An iterator over a matrix anti-diagonal, from top-right to bottom-left.\n\n\
+    This struct is created by the [`antidiag`](MatrixExt::antidiag) method on [`MatrixExt`]."]
+    AntiDiag {/*no mut */} { const } MatrixExt get_nth n,
+    #[doc =
+    "An iterator over a mutable matrix anti-diagonal, from top-right to bottom-left.\n\n\
+    This struct is created by the [`antidiag_mut`](MatrixMutExt::antidiag_mut) method on [`MatrixMutExt`]."]
+    AntiDiagMut { mut } { mut } MatrixMutExt get_nth_mut n;
+    |m: &M, n| {
+        let len = m.antidiag_len(n);
+        if len == 0 {
+            return (1, 0)
+        }
+        // The `n`-th anti-diagonal holds the elements at `(i, j)` with `i + j == n`.
+        let cols = m.num_cols();
+        let start = n.saturating_sub(cols - 1) * cols + ::core::cmp::min(n, cols - 1);
+        (start, start + (len - 1) * (cols - 1))
+    } ;
+    fn increment(&self, i: usize) -> usize {
+        // A single column only has one element on each anti-diagonal.
+        i + ::core::cmp::max(self.matrix().row_len().saturating_sub(1), 1)
+    } ;
+    fn len(&self) -> usize {
+        if self.i > self.irev {
+            return 0
+        }
+        let step = ::core::cmp::max(self.matrix().row_len().saturating_sub(1), 1);
+        (self.irev - self.i) / step + 1
+    } ;
     fn next_back(&mut self) -> Option<Self::Item> {
         if self.i > self.irev {
             return None
         }
         let j = self.irev;
-        self.irev -= self.use_matrix().row_len() + 1;
+        let step = ::core::cmp::max(self.use_matrix().row_len().saturating_sub(1), 1);
+        match j.checked_sub(step) {
+            Some(k) => self.irev = k,
+            None => self.i = j + 1,
+        }
 
         // SAFETY: Nothing else points to or will point to the contents of this iterator.
         self.get_nth(j)
     }
 }
 
+/// Number of cells whose subscripts sum has the given `parity`, among the first `n` cells
+/// (in *Row Major Order*) of a matrix with `cols` columns.
+fn checkered_count(n: usize, cols: usize, parity: usize) -> usize {
+    let (q, rem) = (n / cols, n % cols);
+    // With an odd number of columns, rows starting with the right color have one more cell.
+    let full = q * (cols / 2) + if cols % 2 == 1 { (q + 1 - parity) / 2 } else { 0 };
+    let first = (parity + q) % 2;
+    full + if rem > first { (rem - first).div_ceil(2) } else { 0 }
+}
+
+iter!{
+    #[doc =
+    "An iterator over the cells of one color of a matrix seen as a checkerboard, in *Row Major Order*.\n\n\
+    This struct is created by the [`black_cells`](MatrixExt::black_cells) and [`white_cells`](MatrixExt::white_cells) methods on [`MatrixExt`]."]
+    Checkered {/*no mut */} { const } MatrixExt get_nth parity,
+    #[doc =
+    "An iterator over the mutable cells of one color of a matrix seen as a checkerboard, in *Row Major Order*.\n\n\
+    This struct is created by the [`black_cells_mut`](MatrixMutExt::black_cells_mut) and [`white_cells_mut`](MatrixMutExt::white_cells_mut) methods on [`MatrixMutExt`]."]
+    CheckeredMut { mut } { mut } MatrixMutExt get_nth_mut parity;
+    |m: &M, parity: usize| {
+        let (rows, cols) = m.shape();
+        let size = rows * cols;
+        if parity >= size {
+            return (1, 0)
+        }
+        let last = if (rows - 1 + cols - 1) % 2 == parity { size - 1 } else { size - 2 };
+        (parity, last)
+    } ;
+    fn increment(&self, i: usize) -> usize {
+        let cols = self.matrix().row_len();
+        let j = i % cols;
+        if j + 2 < cols {
+            return i + 2
+        }
+        // The color alternates from a row to the next one.
+        let next_row = i - j + cols;
+        let j = (j + 1) % 2;
+        if j < cols { next_row + j } else { next_row + cols }
+    } ;
+    fn len(&self) -> usize {
+        if self.i > self.irev {
+            return 0
+        }
+        let cols = self.matrix().row_len();
+        let (i, j) = (self.i / cols, self.i % cols);
+        let parity = (i + j) % 2;
+        checkered_count(self.irev + 1, cols, parity) - checkered_count(self.i, cols, parity)
+    } ;
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.i > self.irev {
+            return None
+        }
+        let n = self.irev;
+        let cols = self.use_matrix().row_len();
+        let j = n % cols;
+        let prev = if j >= 2 {
+            Some(n - 2)
+        }
+        else if cols == 1 {
+            n.checked_sub(2)
+        }
+        else {
+            // Last cell of the same color on the previous row.
+            let j = if (cols - 1) % 2 == (j + 1) % 2 { cols - 1 } else { cols - 2 };
+            (n - n % cols).checked_sub(cols).map(|start| start + j)
+        };
+        match prev {
+            Some(k) => self.irev = k,
+            None => self.i = n + 1,
+        }
+
+        // SAFETY: Nothing else points to or will point to the contents of this iterator.
+        self.get_nth(n)
+    }
+}
 
 dimensional_iterator!{ 
     Rows, const, { /* no mut */}, MatrixExt,
@@ -561,18 +770,327 @@ dimensional_iterator!{
     diag_mut, num_diags 
 }
 
+dimensional_iterator!{ 
+    AntiDiags, const, { /* no mut */ }, MatrixExt,
+    AntiDiag<'a, M>,
+    antidiag, num_antidiags 
+}
+
+dimensional_iterator!{ 
+    AntiDiagsMut, mut, { mut }, MatrixMutExt,
+    AntiDiagMut<'a, M>,
+    antidiag_mut, num_antidiags 
+}
+
+
+macro_rules! dimensional_get {
+    ($($w:ident, $outElem:ty, $callfn:ident, $axis:literal;)*) => {$(
+        impl<'a, M: MatrixExt> $w<'a, M> {
+            #[doc = concat!("Returns the ", $axis, " `n` places after the next one to be yielded, without advancing the iterator.")]
+            ///
+            /// Returns `None` if it does not exist.
+            #[inline]
+            pub fn get(&self, n: usize) -> Option<$outElem> {
+                let m: &'a M = unsafe { &*self.m };
+                m.$callfn(self.n.checked_add(n)?)
+            }
+        }
+    )*};
+}
+
+dimensional_get! {
+    Rows, Row<'a, M>, row, "row";
+    Columns, Column<'a, M>, col, "column";
+    Diags, Diag<'a, M>, diag, "diagonal";
+    AntiDiags, AntiDiag<'a, M>, antidiag, "anti-diagonal";
+}
+
+/// Access to the next chunk of rows, or `None` if there are no more rows.
+#[inline]
+fn next_chunk(start: &mut usize, size: usize, rows: usize) -> Option<Submatrix<Range<usize>, RangeFull>> {
+    if *start >= rows {
+        return None
+    }
+    let end = ::core::cmp::min(*start + size, rows);
+    let chunk = Submatrix(*start..end, ..);
+    *start = end;
+    Some(chunk)
+}
+
+/// An iterator over groups of consecutive rows of a matrix, each of them seen as a matrix.
+///
+/// This struct is created by the [`rows_chunks`](MatrixExt::rows_chunks) method on [`MatrixExt`].
+/// See its documentation for more.
+#[derive(Debug)]
+pub struct RowsChunks<'a, M: MatrixExt> {
+    m: *const M,
+    start: usize,
+    size: usize,
+    _marker: PhantomData<&'a M>,
+}
+
+impl<'a, M: MatrixExt> RowsChunks<'a, M> {
+    pub(crate) fn new(m: &'a M, size: usize) -> Self {
+        Self { m, start: 0, size, _marker: PhantomData }
+    }
+}
+
+impl<'a, M: MatrixExt> Iterator for RowsChunks<'a, M> {
+    type Item = Access<'a, M, Submatrix<Range<usize>, RangeFull>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let m: &'a M = unsafe { &*self.m };
+        let chunk = next_chunk(&mut self.start, self.size, m.num_rows())?;
+        Some(Access::new(m, chunk))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = unsafe { &*self.m }.num_rows().saturating_sub(self.start).div_ceil(self.size);
+        (len, Some(len))
+    }
+}
+
+impl<'a, M: MatrixExt> ExactSizeIterator for RowsChunks<'a, M> {}
+
+impl<'a, M: MatrixExt> FusedIterator for RowsChunks<'a, M> {}
+
+unsafe impl<'a, M: MatrixExt> Send for RowsChunks<'a, M>
+where M: Sync {}
+
+unsafe impl<'a, M: MatrixExt> Sync for RowsChunks<'a, M>
+where M: Sync {}
+
+/// An iterator over groups of consecutive rows of a matrix (mutable), each of them seen as a matrix.
+///
+/// Chunks are disjoint parts of the storage of the matrix, so they can be modified at the same time.
+///
+/// This struct is created by the [`rows_chunks_mut`](MatrixMutExt::rows_chunks_mut) method on [`MatrixMutExt`].
+/// See its documentation for more.
+#[derive(Debug)]
+pub struct RowsChunksMut<'a, T> {
+    rest: &'a mut [T],
+    rows: usize,
+    cols: usize,
+    size: usize,
+}
+
+impl<'a, T> RowsChunksMut<'a, T> {
+    pub(crate) fn new(slice: &'a mut [T], (rows, cols): (usize, usize), size: usize) -> Self {
+        Self { rest: slice, rows, cols, size }
+    }
+}
+
+impl<'a, T> Iterator for RowsChunksMut<'a, T> {
+    type Item = RowsChunkMut<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rows == 0 {
+            return None
+        }
+        let rows = ::core::cmp::min(self.size, self.rows);
+        let (data, rest) = ::core::mem::take(&mut self.rest).split_at_mut(rows * self.cols);
+        self.rest = rest;
+        self.rows -= rows;
+        Some(RowsChunkMut { data, rows, cols: self.cols })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.rows.div_ceil(self.size);
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for RowsChunksMut<'a, T> {}
+
+impl<'a, T> FusedIterator for RowsChunksMut<'a, T> {}
+
+/// A group of consecutive rows of a matrix (mutable), seen as a matrix.
+///
+/// This struct is yielded by [`RowsChunksMut`].
+#[derive(Debug)]
+pub struct RowsChunkMut<'a, T> {
+    data: &'a mut [T],
+    rows: usize,
+    cols: usize,
+}
+
+impl<'a, T> MatrixExt for RowsChunkMut<'a, T> {
+    type Element = T;
+
+    #[inline]
+    fn num_rows(&self) -> usize { self.rows }
+
+    #[inline]
+    fn num_cols(&self) -> usize { self.cols }
+
+    #[inline]
+    fn get(&self, row: usize, column: usize) -> Option<&T> {
+        if row >= self.rows || column >= self.cols {
+            return None
+        }
+        self.data.get(row * self.cols + column)
+    }
+}
+
+impl<'a, T> MatrixMutExt for RowsChunkMut<'a, T> {
+    #[inline]
+    fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        if row >= self.rows || column >= self.cols {
+            return None
+        }
+        self.data.get_mut(row * self.cols + column)
+    }
+}
+
+impl<'a, T> MatrixSlices for RowsChunkMut<'a, T> {
+    #[inline]
+    fn as_flat_slice(&self) -> &[T] { self.data }
+}
+
+impl<'a, T> MatrixSlicesMut for RowsChunkMut<'a, T> {
+    #[inline]
+    fn as_flat_slice_mut(&mut self) -> &mut [T] { self.data }
+}
+
+
+/// An iterator over the columns of a matrix as owned vectors, copied a block of columns at a time.
+///
+/// This struct is created by the [`cols_buffered`](MatrixExt::cols_buffered) method on [`MatrixExt`].
+/// See its documentation for more.
+#[derive(Debug)]
+pub struct ColsBuffered<'a, M: MatrixExt> {
+    m: &'a M,
+    /// Index of the first column which is not buffered yet.
+    next: usize,
+    /// Buffered columns, in reverse order.
+    buffer: Vec<Vec<M::Element>>,
+}
+
+impl<'a, M: MatrixExt> ColsBuffered<'a, M> {
+    /// Number of columns copied at once.
+    pub const BLOCK: usize = 16;
+
+    pub(crate) fn new(m: &'a M) -> Self {
+        Self { m, next: 0, buffer: Vec::new() }
+    }
+}
+
+impl<'a, M: MatrixExt> Iterator for ColsBuffered<'a, M>
+where M::Element: Clone
+{
+    type Item = Vec<M::Element>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buffer.is_empty() {
+            let (rows, cols) = self.m.shape();
+            let width = Self::BLOCK.min(cols - self.next);
+            if width == 0 {
+                return None
+            }
+            self.buffer.resize_with(width, || Vec::with_capacity(rows));
+            // Each row is read once for the whole block.
+            for i in 0..rows {
+                for (k, col) in self.buffer.iter_mut().rev().enumerate() {
+                    col.push(self.m.get(i, self.next + k).unwrap().clone());
+                }
+            }
+            self.next += width;
+        }
+        self.buffer.pop()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.buffer.len() + (self.m.num_cols() - self.next);
+        (len, Some(len))
+    }
+}
+
+impl<'a, M: MatrixExt> ExactSizeIterator for ColsBuffered<'a, M>
+where M::Element: Clone {}
+
+impl<'a, M: MatrixExt> FusedIterator for ColsBuffered<'a, M>
+where M::Element: Clone {}
+
+
+/// An iterator over the subscripts of the elements which differ between two matrices of the same shape,
+/// in *Row Major Order*.
+///
+/// This `struct` is created by the [`positions_ne`](crate::MatrixExt::positions_ne) method on `MatrixExt`.
+#[derive(Debug)]
+pub struct PositionsNe<'a, A: MatrixExt, B: MatrixExt> {
+    a: &'a A,
+    b: &'a B,
+    /// Index (in Row Major Order) of the next element to compare.
+    n: usize,
+}
+
+impl<'a, A: MatrixExt, B: MatrixExt> PositionsNe<'a, A, B> {
+    pub(crate) fn new(a: &'a A, b: &'a B) -> Self {
+        if a.shape() != b.shape() {
+            panic!("Cannot compare a {:?} matrix with a {:?} matrix.", a.shape(), b.shape())
+        }
+        Self { a, b, n: 0 }
+    }
+}
+
+impl<'a, A: MatrixExt, B: MatrixExt> Clone for PositionsNe<'a, A, B> {
+    #[inline]
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, A: MatrixExt, B: MatrixExt> Copy for PositionsNe<'a, A, B> {}
+
+impl<'a, A, B> Iterator for PositionsNe<'a, A, B>
+where
+    A: MatrixExt,
+    B: MatrixExt,
+    A::Element: PartialEq<B::Element>,
+{
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cols = self.a.num_cols();
+        while self.n < self.a.size() {
+            let (i, j) = (self.n / cols, self.n % cols);
+            self.n += 1;
+            if self.a.get(i, j).unwrap() != self.b.get(i, j).unwrap() {
+                return Some((i, j))
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.a.size() - self.n))
+    }
+}
+
+impl<'a, A, B> FusedIterator for PositionsNe<'a, A, B>
+where
+    A: MatrixExt,
+    B: MatrixExt,
+    A::Element: PartialEq<B::Element>,
+{}
 
 /// An iterator that yields an element of the a matrix-like `struct` along
 /// with the subscripts of that element.
+#[derive(Clone, Debug)]
 pub struct Enumerator<I> {
     iter: I,
     jmp: usize,
     i: usize,
     j: usize,
+    /// Index (in Row Major Order) following the last element not yet yielded from the back.
+    back: usize,
 }
-impl<I> Enumerator<I> {
+impl<I: ExactSizeIterator> Enumerator<I> {
     pub(crate) fn new(iter: I, jmp: usize) -> Self {
         Self {
+            back: iter.len(),
             iter,
             jmp,
             i: 0,
@@ -604,6 +1122,16 @@ where
 {
     fn len(&self) -> usize {  self.iter.len()  }
 }
+impl<I> DoubleEndedIterator for Enumerator<I>
+where
+    I: DoubleEndedIterator
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let elem = self.iter.next_back()?;
+        self.back -= 1;
+        Some((self.back / self.jmp, self.back % self.jmp, elem))
+    }
+}
 impl<I: FusedIterator> FusedIterator for Enumerator<I> {}
 
 
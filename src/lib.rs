@@ -6,13 +6,21 @@
 //!
 //! # Features
 //! * **impls** (default): Enables implementation of `MatrixExt` and `MatrixMutExt` for the standard 2D array `[[T; N]; M]`.
+//! * **derive**: Enables `#[derive(MatrixExt, MatrixMutExt)]` for structs wrapping a matrix or storing elements in *Row Major Order*.
+//! * **rand**: Enables random selection of elements with the [`rand`](https://docs.rs/rand) crate.
+//! * **num-complex**: Enables conjugate transposition of matrices of [`num_complex::Complex`](https://docs.rs/num-complex) numbers.
+//! * **memmap**: Enables `MmapMatrix`, a read-only matrix viewing a memory-mapped file.
 //!
 //! [`Row Major Order`]: https://en.m.wikipedia.org/wiki/Row-_and_column-major_order
 
 pub mod access;
+pub mod cursor;
 pub mod iterators;  
+pub mod matrices;
+pub mod ops;
 pub mod req;
 pub mod strategies;
+pub mod wrappers;
 
 pub mod prelude {
     pub use crate::{MatrixExt, MatrixMutExt};
@@ -25,6 +33,9 @@ pub mod prelude {
 #[cfg(feature = "impls")]
 mod impls;
 
+#[cfg(feature = "derive")]
+pub use matrixable_derive::{ MatrixExt, MatrixMutExt };
+
 extern crate alloc;
 extern crate std;
 
@@ -50,7 +61,10 @@ pub fn print_diagonals_debug<M: MatrixExt> (p: &M) where <M as MatrixExt>::Eleme
 
 
 use crate::iterators::*;
-use crate::access::{Access, AccessMut};
+use crate::access::{Access, AccessMut, AccessOwned};
+use crate::cursor::{Cursor, CursorMut};
+use crate::matrices::{ ColCache, IntegralImage };
+use crate::wrappers::{ CellMatrix, MatrixDisplay };
 use req::*;
 
 /// This trait provides methods and tools for accessing data in matrix-like structures.
@@ -248,6 +262,22 @@ pub trait MatrixExt
     #[inline]
     fn num_diags(&self) -> usize { self.num_cols().saturating_sub(1) + self.num_rows() }
 
+    /// Returns the number of anti-diagonals.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = [
+    ///     [1, 2, 3],
+    ///     [2, 3, 4],
+    /// ];
+    ///
+    /// assert_eq!(4, m.num_antidiags());
+    /// ```
+    #[inline]
+    fn num_antidiags(&self) -> usize { self.num_diags() }
+
     /// Returns the length of a row.
     #[inline(always)]
     fn row_len(&self) -> usize { self.num_cols() }
@@ -280,31 +310,49 @@ pub trait MatrixExt
     ///
     /// assert_eq!(0, m.diag_len(6));
     ///
+    /// let wide = [[0, 0, 0, 0]];
+    /// assert_eq!(1, wide.diag_len(0));
+    /// assert_eq!(1, wide.diag_len(3));
+    ///
     /// let empty: [[u8; 0]; 1] = [[]]; 
     /// assert_eq!(0, empty.diag_len(0));
     /// ```
-    fn diag_len(&self, mut n: usize) -> usize {
+    fn diag_len(&self, n: usize) -> usize {
         let (rows, cols) = self.shape();
         // num_diags()
         let ndiags = cols.saturating_sub(1) + rows;
         if self.is_empty() || n >= ndiags {
             return 0;
         }
-        let main_diag = rows - 1;
-        n = if n >= main_diag {
-           // Use its symmetric to calculate length.
-           ndiags - n - 1
-        }
-        else {
-            n
-         };
-        
-        if n > cols {
-            // Cut the non existing columns.
-            n -= n - cols;
-        }
-        // +1 because diag index statts from 0.
-        n + 1
+        // Diagonals grow by one element from each corner (counting from both ends),
+        // up to the smallest dimension of the matrix.
+        (n + 1).min(ndiags - n).min(rows).min(cols)
+    }
+
+    /// Gives the length of an anti-diagonal. 
+    /// Returns 0 if the matrix is empty or if the anti-diagonal 
+    /// indexed by `n` does not exist.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = [
+    ///     [0, 0, 0],
+    ///     [0, 0, 0],
+    /// ];
+    /// 
+    /// assert_eq!(1, m.antidiag_len(0));
+    /// assert_eq!(2, m.antidiag_len(1));
+    /// assert_eq!(2, m.antidiag_len(2));
+    /// assert_eq!(1, m.antidiag_len(3));
+    ///
+    /// assert_eq!(0, m.antidiag_len(4));
+    /// ```
+    #[inline]
+    fn antidiag_len(&self, n: usize) -> usize {
+        // Anti-diagonals have the same lengths as diagonals, only mirrored.
+        self.diag_len(n)
     }
     
     /// Checks if the provided subscripts point to an element inside the matrix.
@@ -538,6 +586,12 @@ pub trait MatrixExt
     }
 
     /// Returns an iterator over the elements of the `i`-th row, without doing bound checking.
+    ///
+    /// For a safe alternative see [`row`](#method.row).
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is *[undefined behavior](https://doc.rust-lang.org/reference/behavior-considered-undefined.html)*.
     unsafe fn row_unchecked(&self, i: usize) -> Row<'_, Self> 
     where Self: Sized
     {
@@ -576,6 +630,12 @@ pub trait MatrixExt
     }
 
     /// Returns an iterator over the elements of the `j`-th column, without doing bound checking.
+    ///
+    /// For a safe alternative see [`col`](#method.col).
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is *[undefined behavior](https://doc.rust-lang.org/reference/behavior-considered-undefined.html)*.
     unsafe fn col_unchecked(&self, j: usize) -> Column<'_, Self> 
     where Self: Sized
     {
@@ -615,6 +675,12 @@ pub trait MatrixExt
     }
     
     /// Returns an iterator over the elements of the `n`-th diagonal, without doing bound checking.
+    ///
+    /// For a safe alternative see [`diag`](#method.diag).
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is *[undefined behavior](https://doc.rust-lang.org/reference/behavior-considered-undefined.html)*.
     unsafe fn diag_unchecked(&self, n: usize) -> Diag<'_, Self> 
     where Self: Sized
     {
@@ -639,13 +705,199 @@ pub trait MatrixExt
     /// assert_eq!(Some(&2), diag.next());
     /// assert_eq!(Some(&3), diag.next());
     /// assert_eq!(None, diag.next());
+    ///
+    /// // It starts at the top-left corner whatever the shape of the matrix.
+    /// let wide = [[1, 2, 3], [4, 5, 6]];
+    /// assert_eq!(vec![&1, &5], wide.main_diag().collect::<Vec<_>>());
+    ///
+    /// let tall = [[1, 2], [3, 4], [5, 6]];
+    /// assert_eq!(vec![&1, &4], tall.main_diag().collect::<Vec<_>>());
     /// ```
     fn main_diag(&self) -> Diag<'_, Self> 
     where Self: Sized {
-        let n = ::core::cmp::min(self.num_rows(), self.num_cols());
+        let n = self.num_rows();
         Diag::new(self, n.saturating_sub(1))
     }
+
+    /// Returns an iterator over the elements of the `n`-th anti-diagonal, from top-right to bottom-left.
+    ///
+    /// Anti-diagonals are numbered from the top-left corner to the bottom-right corner:
+    /// the `n`-th one holds all elements at position `(i, j)` with `i + j == n`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = &[
+    ///     [1, 4, 6],
+    ///     [7, 2, 5],
+    ///     [9, 8, 3]
+    /// ];
+    /// 
+    /// let mut antidiag = m.antidiag(1).unwrap();
+    /// assert_eq!(Some(&4), antidiag.next());
+    /// assert_eq!(Some(&7), antidiag.next());
+    /// assert_eq!(None, antidiag.next());
+    ///
+    /// assert_eq!(vec![&5, &8], m.antidiag(3).unwrap().collect::<Vec<_>>());
+    /// assert!(m.antidiag(5).is_none());
+    /// ```
+    #[inline]
+    fn antidiag(&self, n: usize) -> Option<AntiDiag<'_, Self>>
+    where Self: Sized
+    {
+        if n >= self.num_antidiags() {
+            None
+        }
+        else {
+            Some(AntiDiag::new(self, n))
+        }
+    }
+
+    /// Returns the main anti-diagonal i.e. all elements at position `(i, cols - 1 - i)`, from top-right to bottom-left.
+    //
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = &[
+    ///     [2, 7, 6],
+    ///     [9, 5, 1],
+    ///     [4, 3, 8]
+    /// ];
+    ///
+    /// // A magic square check.
+    /// assert_eq!(15, m.main_diag().sum::<i32>());
+    /// assert_eq!(15, m.main_antidiag().sum::<i32>());
+    ///
+    /// let mut antidiag = m.main_antidiag();
+    /// assert_eq!(Some(&6), antidiag.next());
+    /// assert_eq!(Some(&4), antidiag.next_back());
+    /// assert_eq!(Some(&5), antidiag.next());
+    /// assert_eq!(None, antidiag.next());
+    /// ```
+    fn main_antidiag(&self) -> AntiDiag<'_, Self> 
+    where Self: Sized {
+        let n = self.num_cols();
+        AntiDiag::new(self, n.saturating_sub(1))
+    }
+
+    /// Returns an iterator over the elements of the diagonal at offset `k` from the main diagonal,
+    /// starting from top-left to bottom-right.
+    ///
+    /// `k = 0` is the main diagonal; a positive `k` gives the diagonal starting at `(0, k)` (above the main diagonal)
+    /// and a negative `k` the one starting at `(-k, 0)` (below it).
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = &[
+    ///     [1, 4, 6],
+    ///     [7, 2, 5],
+    ///     [9, 8, 3]
+    /// ];
+    /// 
+    /// assert_eq!(vec![&1, &2, &3], m.diag_offset(0).unwrap().collect::<Vec<_>>());
+    /// assert_eq!(vec![&4, &5], m.diag_offset(1).unwrap().collect::<Vec<_>>());
+    /// assert_eq!(vec![&9], m.diag_offset(-2).unwrap().collect::<Vec<_>>());
+    ///
+    /// assert!(m.diag_offset(3).is_none());
+    /// assert!(m.diag_offset(-3).is_none());
+    /// ```
+    #[inline]
+    fn diag_offset(&self, k: isize) -> Option<Diag<'_, Self>>
+    where Self: Sized
+    {
+        let n = (self.num_rows() as isize - 1).checked_add(k)?;
+        self.diag(usize::try_from(n).ok()?)
+    }
     
+    /// Returns an iterator over groups of `n` consecutive rows, each of them seen as a matrix.
+    ///
+    /// The last group has less than `n` rows if `n` does not divide the number of rows.
+    ///
+    /// # Panics
+    /// Panics if `n` is 0.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = [[1, 2], [3, 4], [5, 6], [7, 8], [9, 10]];
+    ///
+    /// let sums: Vec<i32> = m.rows_chunks(2).map(|chunk| chunk.iter().sum()).collect();
+    /// assert_eq!(vec![10, 26, 19], sums);
+    ///
+    /// let last = m.rows_chunks(2).last().unwrap();
+    /// assert_eq!((1, 2), last.shape());
+    /// ```
+    fn rows_chunks(&self, n: usize) -> RowsChunks<'_, Self>
+    where Self: Sized
+    {
+        if n == 0 {
+            panic!("Chunk size must be greater than zero.")
+        }
+        RowsChunks::new(self, n)
+    }
+
+    /// Returns an iterator over the black cells of the matrix seen as a checkerboard, in *Row Major Order*.
+    ///
+    /// Black cells are those whose subscripts have an even sum, the first element of the matrix being black.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    ///     [7, 8, 9]
+    /// ];
+    ///
+    /// assert_eq!(vec![&1, &3, &5, &7, &9], m.black_cells().collect::<Vec<_>>());
+    /// assert_eq!(vec![&8, &6, &4, &2], m.white_cells().rev().collect::<Vec<_>>());
+    /// ```
+    #[inline]
+    fn black_cells(&self) -> Checkered<'_, Self>
+    where Self: Sized
+    {
+        Checkered::new(self, 0)
+    }
+
+    /// Returns an iterator over the white cells of the matrix seen as a checkerboard, in *Row Major Order*.
+    ///
+    /// White cells are those whose subscripts have an odd sum. See [`black_cells`](#method.black_cells).
+    #[inline]
+    fn white_cells(&self) -> Checkered<'_, Self>
+    where Self: Sized
+    {
+        Checkered::new(self, 1)
+    }
+
+    /// Returns a [`Cursor`] pointing to the element at the intersection of the `i`-th row and the `j`-th column,
+    /// or `None` if those indexes are out of bounds.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = [[1, 2], [3, 4]];
+    /// let mut cursor = m.cursor(0, 0).unwrap();
+    ///
+    /// assert!(!cursor.up());
+    /// assert!(cursor.move_by(1, 1));
+    /// assert_eq!(&4, cursor.current());
+    ///
+    /// assert!(m.cursor(2, 0).is_none());
+    /// ```
+    #[inline]
+    fn cursor(&self, i: usize, j: usize) -> Option<Cursor<'_, Self>>
+    where Self: Sized
+    {
+        Cursor::new(self, i, j)
+    }
+
     /// Returns an iterator which gives the current subscripts of the current element as well as its value.
     /// ```rust
     /// use matrixable::MatrixExt;
@@ -660,6 +912,50 @@ pub trait MatrixExt
     /// assert_eq!(Some((2, 0, &5)), en.next());
     /// assert_eq!(Some((2, 1, &6)), en.next());
     /// assert_eq!(None, en.next());
+    ///
+    /// // It can also be reversed.
+    /// let mut en = m.enumerate().rev();
+    ///
+    /// assert_eq!(Some((2, 1, &6)), en.next());
+    /// assert_eq!(Some((2, 0, &5)), en.next());
+    /// assert_eq!(Some((1, 1, &4)), en.next());
+    /// // `len` gives the number of elements left.
+    /// assert_eq!(3, en.len());
+    ///
+    /// let wide = [[1, 2, 3], [4, 5, 6]];
+    /// let mut en = wide.enumerate();
+    /// en.next();
+    /// assert_eq!(
+    ///     vec![(1, 2, &6), (1, 1, &5), (1, 0, &4), (0, 2, &3), (0, 1, &2)],
+    ///     en.rev().collect::<Vec<_>>()
+    /// );
+    ///
+    /// let empty: [[u8; 0]; 2] = [[], []];
+    /// assert_eq!(0, empty.enumerate().len());
+    /// assert_eq!(None, empty.enumerate().next_back());
+    ///
+    /// // The same goes for the iterators over rows, columns and diagonals.
+    /// let tall = [[1, 2], [3, 4], [5, 6]];
+    /// let mut col = tall.col(1).unwrap();
+    /// assert_eq!(Some(&6), col.next_back());
+    /// assert_eq!(2, col.len());
+    /// assert_eq!(vec![&4, &2], col.rev().collect::<Vec<_>>());
+    ///
+    /// let mut diag = tall.diag(1).unwrap();
+    /// assert_eq!(2, diag.len());
+    /// assert_eq!(Some(&6), diag.next_back());
+    /// assert_eq!(1, diag.len());
+    /// assert_eq!(vec![&3], diag.rev().collect::<Vec<_>>());
+    ///
+    /// let mut row = wide.row(1).unwrap();
+    /// row.next();
+    /// assert_eq!(2, row.len());
+    /// assert_eq!(vec![&6, &5], row.rev().collect::<Vec<_>>());
+    ///
+    /// let mut diag = wide.diag(2).unwrap();
+    /// assert_eq!(vec![&2, &6], diag.clone().collect::<Vec<_>>());
+    /// assert_eq!(Some(&6), diag.next_back());
+    /// assert_eq!(1, diag.len());
     ///```
     fn enumerate(&self) -> Enumerator<Iter<'_, Self>>
     where Self: Sized
@@ -682,7 +978,7 @@ pub trait MatrixExt
     /// assert!(rows.next().is_none());
     ///```
     #[inline]
-    fn rows(&self) -> Rows<Self> where Self: Sized { 
+    fn rows(&self) -> Rows<'_, Self> where Self: Sized { 
         Rows::from(self)
     }
 
@@ -699,9 +995,39 @@ pub trait MatrixExt
     /// assert!(cols.next().is_none());
     ///```
     #[inline]
-    fn cols(&self) -> Columns<Self> where Self: Sized { 
+    fn cols(&self) -> Columns<'_, Self> where Self: Sized { 
         Columns::from(self)
     }
+
+    /// Returns an iterator over the columns of the matrix as owned vectors.
+    ///
+    /// Columns of a matrix stored in *Row Major Order* are scattered in memory. This iterator copies
+    /// blocks of [`ColsBuffered::BLOCK`] columns at once, reading each row of the block in one go,
+    /// which is friendlier to the cache than [`cols`](#method.cols) on large matrices.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6]
+    /// ];
+    ///
+    /// let sums: Vec<i32> = m.cols_buffered().map(|col| col.iter().sum()).collect();
+    /// assert_eq!(vec![5, 7, 9], sums);
+    ///
+    /// let wide = [[0u8; 40]; 3];
+    /// assert_eq!(40, wide.cols_buffered().len());
+    /// ```
+    #[inline]
+    fn cols_buffered(&self) -> ColsBuffered<'_, Self>
+    where
+        Self: Sized,
+        Self::Element: Clone
+    {
+        ColsBuffered::new(self)
+    }
     
     /// Returns an iterator over the diagonals with immutable access to elements.
     /// Examples
@@ -753,10 +1079,36 @@ pub trait MatrixExt
     /// assert!(diags.next().is_none());
     /// ```
     #[inline]
-    fn diags(&self) -> Diags<Self> where Self: Sized {
+    fn diags(&self) -> Diags<'_, Self> where Self: Sized {
         Diags::from(self) 
     }
 
+    /// Returns an iterator over the anti-diagonals of the matrix,
+    /// from the top-left corner to the bottom-right corner.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6]
+    /// ];
+    ///
+    /// let mut antidiags = m.antidiags();
+    /// assert_eq!(4, antidiags.len());
+    ///
+    /// assert_eq!(vec![&1], antidiags.next().unwrap().collect::<Vec<_>>());
+    /// assert_eq!(vec![&2, &4], antidiags.next().unwrap().collect::<Vec<_>>());
+    /// assert_eq!(vec![&3, &5], antidiags.next().unwrap().collect::<Vec<_>>());
+    /// assert_eq!(vec![&6], antidiags.next().unwrap().collect::<Vec<_>>());
+    /// assert!(antidiags.next().is_none());
+    /// ```
+    #[inline]
+    fn antidiags(&self) -> AntiDiags<'_, Self> where Self: Sized {
+        AntiDiags::from(self) 
+    }
+
     /// Returns an array of elements that are one-cell-adjacent to the hypothetic element located
     /// at `i`-th row and `j`-th column.
     /// # Examples
@@ -883,6 +1235,109 @@ pub trait MatrixExt
     where Self: Sized {
         Access::new(self, strategy)
     }
+
+    /// Same as [`access`](#method.access) but takes the matrix by value,
+    /// so that the returned `AccessOwned` does not borrow anything and can be returned from functions.
+    ///
+    /// `AccessOwned` also implements `MatrixMutExt` when the matrix does.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::{ MatrixExt, MatrixMutExt };
+    /// use matrixable::access::AccessOwned;
+    /// use matrixable::strategies::RotateR;
+    ///
+    /// fn rotated(m: [[i32; 3]; 2]) -> AccessOwned<[[i32; 3]; 2], RotateR> {
+    ///     m.into_access(RotateR)
+    /// }
+    ///
+    /// let mut access = rotated([[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(vec![vec![4, 1], vec![5, 2], vec![6, 3]], access.to_vecs());
+    ///
+    /// access.set((0, 0), 40).unwrap();
+    /// assert_eq!([[1, 2, 3], [40, 5, 6]], access.into_inner());
+    /// ```
+    #[inline]
+    fn into_access<S: AccessStrategy<Self>>(self, strategy: S) -> AccessOwned<Self, S>
+    where Self: Sized {
+        AccessOwned::new(self, strategy)
+    }
+
+    /// Returns an access to the rows of the matrix at the given indexes, in that order.
+    ///
+    /// Use [`to_matrix`](#method.to_matrix) on the result to obtain an owned matrix.
+    /// See [`SelectRows`](crate::strategies::SelectRows).
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    ///     [7, 8, 9]
+    /// ];
+    ///
+    /// let selected = m.select_rows(&[2, 0]);
+    /// assert_eq!(Some(&7), selected.get(0, 0));
+    ///
+    /// let owned: [[i32; 3]; 2] = selected.to_matrix();
+    /// assert_eq!([[7, 8, 9], [1, 2, 3]], owned);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if an index is out of bounds.
+    /// ```should_panic
+    /// use matrixable::MatrixExt;
+    ///
+    /// [[1, 2], [3, 4]].select_rows(&[0, 5]);
+    /// ```
+    fn select_rows(&self, rows: &[usize]) -> Access<'_, Self, crate::strategies::SelectRows>
+    where Self: Sized {
+        let nrows = self.num_rows();
+        if let Some(i) = rows.iter().find(|&&i| i >= nrows) {
+            panic!("Cannot select row {} of a {:?} matrix.", i, self.shape())
+        }
+        self.access(crate::strategies::SelectRows(rows.to_vec()))
+    }
+
+    /// Returns an access to the columns of the matrix at the given indexes, in that order.
+    ///
+    /// Use [`to_matrix`](#method.to_matrix) on the result to obtain an owned matrix.
+    /// See [`SelectCols`](crate::strategies::SelectCols).
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6]
+    /// ];
+    ///
+    /// let owned: [[i32; 3]; 2] = m.select_cols(&[0, 0, 2]).to_matrix();
+    /// assert_eq!([[1, 1, 3], [4, 4, 6]], owned);
+    ///
+    /// // Combined with a row selection.
+    /// let corners: [[i32; 2]; 2] = m.select_rows(&[0, 1]).select_cols(&[0, 2]).to_matrix();
+    /// assert_eq!([[1, 3], [4, 6]], corners);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if an index is out of bounds.
+    /// ```should_panic
+    /// use matrixable::MatrixExt;
+    ///
+    /// [[1, 2], [3, 4]].select_cols(&[2]);
+    /// ```
+    fn select_cols(&self, cols: &[usize]) -> Access<'_, Self, crate::strategies::SelectCols>
+    where Self: Sized {
+        let ncols = self.num_cols();
+        if let Some(j) = cols.iter().find(|&&j| j >= ncols) {
+            panic!("Cannot select column {} of a {:?} matrix.", j, self.shape())
+        }
+        self.access(crate::strategies::SelectCols(cols.to_vec()))
+    }
     
     
     /// Converts a matrix into an iterator over rows of the matrix.
@@ -969,26 +1424,995 @@ pub trait MatrixExt
         IntoAxes::from_as_diags(self)
     }
 
-    /// Consumes the matrix an returns an output defined by a `TransformStrategy`.
-    #[inline] 
-    fn transform<S: TransformStrategy<Self>>(self, strategy: &S) -> S::Output  
-    where Self: Sized
-    {
-        strategy.out_of(self)
+    /// Clones the elements of the matrix into a vector of rows.
+    ///
+    /// To move the elements of an owned matrix instead, collect [`into_rows`](#method.into_rows).
+    ///
+    /// # Example
+    /// ```
+    /// use matrixable::MatrixExt;
+    /// use matrixable::strategies::Transpose;
+    /// 
+    /// let m = [[1, 2, 3], [4, 5, 6]];
+    /// 
+    /// assert_eq!(vec![vec![1, 2, 3], vec![4, 5, 6]], m.to_vecs());
+    /// assert_eq!(vec![vec![1, 4], vec![2, 5], vec![3, 6]], m.access(Transpose).to_vecs());
+    /// ```
+    fn to_vecs(&self) -> Vec<Vec<Self::Element>>
+    where Self::Element: Clone
+    {
+        (0..self.num_rows())
+            .map(|i| (0..self.num_cols()).map(|j| self.get(i, j).unwrap().clone()).collect())
+            .collect()
+    }
+
+    /// Clones the elements of the matrix into a vector of diagonals, ordered like [`diags`](#method.diags).
+    ///
+    /// Unlike [`into_diags`](#method.into_diags), this works with any matrix.
+    ///
+    /// # Example
+    /// ```
+    /// use matrixable::MatrixExt;
+    /// use matrixable::strategies::Transpose;
+    ///
+    /// let m = [[1, 2, 3], [4, 5, 6]];
+    ///
+    /// assert_eq!(vec![vec![4], vec![1, 5], vec![2, 6], vec![3]], m.diags_to_vecs());
+    /// assert_eq!(vec![vec![3], vec![2, 6], vec![1, 5], vec![4]], m.access(Transpose).diags_to_vecs());
+    /// ```
+    fn diags_to_vecs(&self) -> Vec<Vec<Self::Element>>
+    where
+        Self: Sized,
+        Self::Element: Clone
+    {
+        self.diags().map(|diag| diag.cloned().collect()).collect()
+    }
+
+    /// Clones the elements of the matrix into a vector of anti-diagonals.
+    ///
+    /// Anti-diagonals are ordered from the top-left corner to the bottom-right corner,
+    /// and each one goes from top-right to bottom-left like [`antidiags`](#method.antidiags).
+    ///
+    /// # Example
+    /// ```
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = [[1, 2, 3], [4, 5, 6]];
+    ///
+    /// assert_eq!(vec![vec![1], vec![2, 4], vec![3, 5], vec![6]], m.anti_diags_to_vecs());
+    ///
+    /// let empty: [[i32; 0]; 2] = [[], []];
+    /// assert!(empty.anti_diags_to_vecs().is_empty());
+    /// ```
+    fn anti_diags_to_vecs(&self) -> Vec<Vec<Self::Element>>
+    where
+        Self: Sized,
+        Self::Element: Clone
+    {
+        self.antidiags().map(|antidiag| antidiag.cloned().collect()).collect()
+    }
+
+    /// Clones the elements of the `i`-th row into a vector, or returns `None` if it does not exist.
+    ///
+    /// # Example
+    /// ```
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = [[1, 2, 3], [4, 5, 6]];
+    ///
+    /// assert_eq!(Some(vec![4, 5, 6]), m.row_to_vec(1));
+    /// assert_eq!(None, m.row_to_vec(2));
+    /// ```
+    fn row_to_vec(&self, i: usize) -> Option<Vec<Self::Element>>
+    where
+        Self: Sized,
+        Self::Element: Clone
+    {
+        Some(self.row(i)?.cloned().collect())
+    }
+
+    /// Clones the elements of the `j`-th column into a vector, or returns `None` if it does not exist.
+    ///
+    /// # Example
+    /// ```
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = [[1, 2, 3], [4, 5, 6]];
+    ///
+    /// assert_eq!(Some(vec![3, 6]), m.col_to_vec(2));
+    /// assert_eq!(None, m.col_to_vec(3));
+    /// ```
+    fn col_to_vec(&self, j: usize) -> Option<Vec<Self::Element>>
+    where
+        Self: Sized,
+        Self::Element: Clone
+    {
+        Some(self.col(j)?.cloned().collect())
+    }
+
+    /// Clones the elements of the `n`-th diagonal into a vector, or returns `None` if it does not exist.
+    ///
+    /// Diagonals are numbered like in [`diag`](#method.diag).
+    ///
+    /// # Example
+    /// ```
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = [[1, 2, 3], [4, 5, 6]];
+    ///
+    /// assert_eq!(Some(vec![1, 5]), m.diag_to_vec(1));
+    /// assert_eq!(Some(vec![3]), m.diag_to_vec(3));
+    /// assert_eq!(None, m.diag_to_vec(4));
+    /// ```
+    fn diag_to_vec(&self, n: usize) -> Option<Vec<Self::Element>>
+    where
+        Self: Sized,
+        Self::Element: Clone
+    {
+        Some(self.diag(n)?.cloned().collect())
+    }
+
+    /// Clones the elements of the matrix into a boxed slice of rows.
+    ///
+    /// # Example
+    /// ```
+    /// use matrixable::MatrixExt;
+    /// 
+    /// let m = [[1, 2, 3], [4, 5, 6]];
+    /// let rows: Box<[Box<[i32]>]> = m.to_boxed_rows();
+    /// 
+    /// assert_eq!(&[4, 5, 6], &*rows[1]);
+    /// ```
+    fn to_boxed_rows(&self) -> alloc::boxed::Box<[alloc::boxed::Box<[Self::Element]>]>
+    where Self::Element: Clone
+    {
+        (0..self.num_rows())
+            .map(|i| (0..self.num_cols()).map(|j| self.get(i, j).unwrap().clone()).collect())
+            .collect()
+    }
+
+    /// Clones the elements of the matrix into a new matrix of the same shape, whose type is chosen by the caller.
+    ///
+    /// # Example
+    /// ```
+    /// use matrixable::MatrixExt;
+    /// use matrixable::strategies::Transpose;
+    /// 
+    /// let m = [[1, 2, 3], [4, 5, 6]];
+    /// 
+    /// let t: [[i32; 2]; 3] = m.access(Transpose).to_matrix();
+    /// assert_eq!([[1, 4], [2, 5], [3, 6]], t);
+    /// ```
+    fn to_matrix<O>(&self) -> O
+    where
+        Self::Element: Clone,
+        O: MatrixExtFromIter<Self::Element>
+    {
+        let mut v = Vec::with_capacity(self.size());
+        for i in 0..self.num_rows() {
+            for j in 0..self.num_cols() {
+                v.push(self.get(i, j).unwrap().clone());
+            }
+        }
+        O::from_iter(v, self.num_cols())
+    }
+
+    /// Clones the rectangular region going from `top_left` to `bottom_right` (both included) into a new matrix.
+    ///
+    /// This is the eager counterpart of an access with the [`Submatrix`](crate::strategies::Submatrix) strategy.
+    /// `None` is returned if a corner is out of bounds or if `bottom_right` is above or left of `top_left`.
+    ///
+    /// # Example
+    /// ```
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    ///     [7, 8, 9]
+    /// ];
+    ///
+    /// let r: [[i32; 2]; 2] = m.copy_region((1, 0), (2, 1)).unwrap();
+    /// assert_eq!([[4, 5], [7, 8]], r);
+    ///
+    /// let c: Option<[[i32; 1]; 3]> = m.copy_region((0, 2), (2, 2));
+    /// assert_eq!(Some([[3], [6], [9]]), c);
+    ///
+    /// assert!(m.copy_region::<[[i32; 1]; 1]>((0, 0), (3, 0)).is_none());
+    /// assert!(m.copy_region::<[[i32; 1]; 1]>((1, 1), (0, 2)).is_none());
+    /// ```
+    fn copy_region<O>(&self, top_left: (usize, usize), bottom_right: (usize, usize)) -> Option<O>
+    where
+        Self::Element: Clone,
+        O: MatrixExtFromIter<Self::Element>
+    {
+        let ((r0, c0), (r1, c1)) = (top_left, bottom_right);
+        if r0 > r1 || c0 > c1 || !self.check(r1, c1) {
+            return None
+        }
+        let mut v = Vec::with_capacity((r1 - r0 + 1) * (c1 - c0 + 1));
+        for i in r0..=r1 {
+            for j in c0..=c1 {
+                v.push(self.get(i, j).unwrap().clone());
+            }
+        }
+        Some(O::from_iter(v, c1 - c0 + 1))
+    }
+
+    /// Returns a copy of the matrix with every element restricted to the interval `[min, max]`.
+    ///
+    /// See [`clamp_elements`](crate::MatrixMutExt::clamp_elements) for the in-place version.
+    ///
+    /// # Panics
+    /// Panics if `min > max`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = [[-0.5, 0.25], [1.5, f64::NAN]];
+    ///
+    /// let c: [[f64; 2]; 2] = m.clamped(&0.0, &1.0);
+    ///
+    /// assert_eq!([0.0, 0.25, 1.0], [c[0][0], c[0][1], c[1][0]]);
+    /// assert!(c[1][1].is_nan());
+    /// ```
+    fn clamped<O>(&self, min: &Self::Element, max: &Self::Element) -> O
+    where
+        Self::Element: PartialOrd + Clone,
+        O: MatrixExtFromIter<Self::Element>
+    {
+        if min > max {
+            panic!("Cannot clamp elements when min is greater than max.")
+        }
+        let mut v = Vec::with_capacity(self.size());
+        for i in 0..self.num_rows() {
+            for j in 0..self.num_cols() {
+                let x = self.get(i, j).unwrap();
+                v.push(if x < min { min } else if x > max { max } else { x }.clone());
+            }
+        }
+        O::from_iter(v, self.num_cols())
+    }
+
+    /// Clones the elements matching the condition, along with their subscripts, in *Row Major Order*.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = [[1, -2, 3], [-4, 5, -6]];
+    ///
+    /// assert_eq!(vec![(0, 1, -2), (1, 0, -4), (1, 2, -6)], m.select_where(|&x| x < 0));
+    /// ```
+    fn select_where(&self, mut f: impl FnMut(&Self::Element) -> bool) -> Vec<(usize, usize, Self::Element)>
+    where Self::Element: Clone
+    {
+        let mut v = Vec::new();
+        for i in 0..self.num_rows() {
+            for j in 0..self.num_cols() {
+                let x = self.get(i, j).unwrap();
+                if f(x) {
+                    v.push((i, j, x.clone()));
+                }
+            }
+        }
+        v
+    }
+
+    /// Clones the elements whose matching element in `mask` is `true`, along with their subscripts, in *Row Major Order*.
+    ///
+    /// # Panics
+    /// Panics if `mask` does not have the same shape as the matrix.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = [['a', 'b'], ['c', 'd']];
+    /// let mask = [[true, false], [false, true]];
+    ///
+    /// assert_eq!(vec![(0, 0, 'a'), (1, 1, 'd')], m.select_where_mask(&mask));
+    /// ```
+    fn select_where_mask<M>(&self, mask: &M) -> Vec<(usize, usize, Self::Element)>
+    where
+        M: MatrixExt<Element = bool>,
+        Self::Element: Clone
+    {
+        if self.shape() != mask.shape() {
+            panic!("Cannot apply a {:?} mask to a {:?} matrix.", mask.shape(), self.shape())
+        }
+        let mut v = Vec::new();
+        for i in 0..self.num_rows() {
+            for j in 0..self.num_cols() {
+                if *mask.get(i, j).unwrap() {
+                    v.push((i, j, self.get(i, j).unwrap().clone()));
+                }
+            }
+        }
+        v
+    }
+
+    /// Clones the rows whose entry in `mask` is `true` into a new matrix.
+    ///
+    /// # Panics
+    /// Panics if the length of `mask` is different from the number of rows.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = [[1, 2], [3, 4], [5, 6]];
+    ///
+    /// let kept: [[i32; 2]; 2] = m.compress_rows(&[true, false, true]);
+    /// assert_eq!([[1, 2], [5, 6]], kept);
+    /// ```
+    fn compress_rows<O>(&self, mask: &[bool]) -> O
+    where
+        Self::Element: Clone,
+        O: MatrixExtFromIter<Self::Element>
+    {
+        if mask.len() != self.num_rows() {
+            panic!("Cannot apply a mask of length {} to the rows of a {:?} matrix.", mask.len(), self.shape())
+        }
+        let cols = self.num_cols();
+        let mut v = Vec::new();
+        for i in (0..self.num_rows()).filter(|&i| mask[i]) {
+            v.extend((0..cols).map(|j| self.get(i, j).unwrap().clone()));
+        }
+        O::from_iter(v, cols)
+    }
+
+    /// Clones the elements of the matrix into a 2D array of `R` rows and `C` columns.
+    ///
+    /// # Error
+    /// An error is returned if the matrix does not have `R` rows and `C` columns.
+    ///
+    /// # Example
+    /// ```
+    /// use matrixable::MatrixExt;
+    /// use matrixable::strategies::RotateR;
+    /// 
+    /// let m = [[1, 2, 3], [4, 5, 6]];
+    /// let rotated = m.access(RotateR);
+    /// 
+    /// assert_eq!(Ok([[4, 1], [5, 2], [6, 3]]), rotated.to_array::<3, 2>());
+    /// assert_eq!(Err("Cannot convert into an array of different shape."), rotated.to_array::<2, 3>());
+    /// ```
+    fn to_array<const R: usize, const C: usize>(&self) -> Result<[[Self::Element; C]; R], &'static str>
+    where Self::Element: Clone
+    {
+        if self.shape() != (R, C) {
+            return Err("Cannot convert into an array of different shape.")
+        }
+        Ok(::core::array::from_fn(|i| ::core::array::from_fn(|j| self.get(i, j).unwrap().clone())))
+    }
+
+    /// Returns the conjugate transpose (Hermitian transpose) of a matrix of complex numbers.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    /// use num_complex::Complex;
+    ///
+    /// let m = [
+    ///     [Complex::new(1, 1), Complex::new(2, -3)],
+    ///     [Complex::new(0, 4), Complex::new(5,  0)],
+    ///     [Complex::new(6, 0), Complex::new(7,  1)],
+    /// ];
+    ///
+    /// let h: [[Complex<i32>; 3]; 2] = m.hermitian();
+    /// assert_eq!([
+    ///     [Complex::new(1, -1), Complex::new(0, -4), Complex::new(6,  0)],
+    ///     [Complex::new(2,  3), Complex::new(5,  0), Complex::new(7, -1)],
+    /// ], h);
+    /// ```
+    #[cfg(feature = "num-complex")]
+    fn hermitian<T, O>(&self) -> O
+    where
+        Self: MatrixExt<Element = num_complex::Complex<T>>,
+        T: Clone + ::core::ops::Neg<Output = T>,
+        O: MatrixExtFromIter<num_complex::Complex<T>>
+    {
+        let mut v = Vec::with_capacity(self.size());
+        for j in 0..self.num_cols() {
+            for i in 0..self.num_rows() {
+                let z = self.get(i, j).unwrap();
+                v.push(num_complex::Complex::new(z.re.clone(), -z.im.clone()));
+            }
+        }
+        O::from_iter(v, self.num_rows())
+    }
+
+    /// Consumes the matrix an returns an output defined by a `TransformStrategy`.
+    #[inline] 
+    fn transform<S: TransformStrategy<Self>>(self, strategy: &S) -> S::Output  
+    where Self: Sized
+    {
+        strategy.out_of(self)
+    }
+
+    /// Clones each element of the matrix into a preallocated matrix of the same shape.
+    ///
+    /// # Error
+    /// An error is returned if `out` does not have the same shape as this matrix.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    /// use matrixable::strategies::FlipH;
+    ///
+    /// let m = [[1, 2, 3], [4, 5, 6]];
+    /// let mut out = [[0; 3]; 2];
+    ///
+    /// m.access(FlipH).copy_into(&mut out).unwrap();
+    /// assert_eq!([[3, 2, 1], [6, 5, 4]], out);
+    ///
+    /// let mut wrong_shape = [[0; 2]; 3];
+    /// assert_eq!(Err("Cannot copy into a matrix of different shape."), m.copy_into(&mut wrong_shape));
+    /// ```
+    fn copy_into<M: MatrixMutExt<Element = Self::Element>>(&self, out: &mut M) -> Result<(), &'static str>
+    where Self::Element: Clone
+    {
+        if self.shape() != out.shape() {
+            return Err("Cannot copy into a matrix of different shape.")
+        }
+        for i in 0..self.num_rows() {
+            for j in 0..self.num_cols() {
+                out.get_mut(i, j).unwrap().clone_from(self.get(i, j).unwrap());
+            }
+        }
+        Ok(())
+    }
+
+    /// Clones the transpose of the matrix into a preallocated matrix, without any allocation.
+    ///
+    /// # Error
+    /// An error is returned if `out` does not have the shape of the transposed matrix.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = [[1, 2, 3], [4, 5, 6]];
+    /// let mut out = [[0; 2]; 3];
+    ///
+    /// m.transpose_into(&mut out).unwrap();
+    /// assert_eq!([[1, 4], [2, 5], [3, 6]], out);
+    ///
+    /// let mut wrong_shape = [[0; 3]; 2];
+    /// assert_eq!(Err("Cannot transpose into a matrix of incompatible shape."), m.transpose_into(&mut wrong_shape));
+    /// ```
+    fn transpose_into<M: MatrixMutExt<Element = Self::Element>>(&self, out: &mut M) -> Result<(), &'static str>
+    where Self::Element: Clone
+    {
+        if (self.num_cols(), self.num_rows()) != out.shape() {
+            return Err("Cannot transpose into a matrix of incompatible shape.")
+        }
+        for i in 0..self.num_rows() {
+            for j in 0..self.num_cols() {
+                out.get_mut(j, i).unwrap().clone_from(self.get(i, j).unwrap());
+            }
+        }
+        Ok(())
+    }
+
+    /// Computes the two-dimensional prefix sums of the matrix.
+    ///
+    /// Element `(i, j)` of the output is the sum of the elements in rows `0..=i` and columns `0..=j`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6]
+    /// ];
+    ///
+    /// let sums: [[i32; 3]; 2] = m.prefix_sums();
+    /// assert_eq!([[1, 3, 6], [5, 12, 21]], sums);
+    /// ```
+    fn prefix_sums<O>(&self) -> O
+    where
+        Self::Element: Copy + Default + ::core::ops::Add<Output = Self::Element>,
+        O: MatrixExtFromIter<Self::Element>
+    {
+        let cols = self.num_cols();
+        let mut v: Vec<Self::Element> = Vec::with_capacity(self.size());
+        for i in 0..self.num_rows() {
+            let mut row_sum = Self::Element::default();
+            for j in 0..cols {
+                row_sum = row_sum + *self.get(i, j).unwrap();
+                let above = if i == 0 { Self::Element::default() } else { v[(i - 1) * cols + j] };
+                v.push(above + row_sum);
+            }
+        }
+        O::from_iter(v, cols)
+    }
+
+    /// Computes the two-dimensional prefix sums of the matrix, allowing to get the sum of any
+    /// rectangular region in constant time with [`region_sum`](crate::matrices::IntegralImage::region_sum).
+    #[inline]
+    fn integral_image(&self) -> IntegralImage<Self::Element>
+    where
+        Self: Sized,
+        Self::Element: Copy + Default + ::core::ops::Add<Output = Self::Element>
+    {
+        IntegralImage::new(self)
+    }
+
+    /// Clones the matrix into a [`ColCache`], storing it in *Column Major Order* while keeping its orientation.
+    ///
+    /// This trades memory for locality when an algorithm goes through the columns many times.
+    #[inline]
+    fn col_cache(&self) -> ColCache<Self::Element>
+    where
+        Self: Sized,
+        Self::Element: Clone
+    {
+        ColCache::new(self)
+    }
+
+    /// Computes the minimum of each `h` x `w` window of the matrix.
+    ///
+    /// Element `(i, j)` of the output is the minimum of the elements in rows `i..i+h` and columns `j..j+w`,
+    /// so the output has `rows - h + 1` rows and `cols - w + 1` columns.
+    /// Each element is compared a constant number of times on average, whatever the size of the window.
+    ///
+    /// # Panics
+    /// Panics if the window is empty or if it does not fit inside the matrix.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = [
+    ///     [3, 1, 4, 1],
+    ///     [5, 9, 2, 6],
+    ///     [5, 3, 5, 8]
+    /// ];
+    ///
+    /// let min: [[i32; 3]; 2] = m.window_min(2, 2);
+    /// assert_eq!([[1, 1, 1], [3, 2, 2]], min);
+    /// ```
+    fn window_min<O>(&self, h: usize, w: usize) -> O
+    where
+        Self: Sized,
+        Self::Element: Clone + PartialOrd,
+        O: MatrixExtFromIter<Self::Element>
+    {
+        window_extremum(self, h, w, |a, b| a <= b)
+    }
+
+    /// Computes the maximum of each `h` x `w` window of the matrix.
+    ///
+    /// Element `(i, j)` of the output is the maximum of the elements in rows `i..i+h` and columns `j..j+w`,
+    /// so the output has `rows - h + 1` rows and `cols - w + 1` columns.
+    /// Each element is compared a constant number of times on average, whatever the size of the window.
+    ///
+    /// # Panics
+    /// Panics if the window is empty or if it does not fit inside the matrix.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = [
+    ///     [3, 1, 4, 1],
+    ///     [5, 9, 2, 6],
+    ///     [5, 3, 5, 8]
+    /// ];
+    ///
+    /// let max: [[i32; 2]; 3] = m.window_max(1, 3);
+    /// assert_eq!([[4, 4], [9, 9], [5, 8]], max);
+    /// ```
+    fn window_max<O>(&self, h: usize, w: usize) -> O
+    where
+        Self: Sized,
+        Self::Element: Clone + PartialOrd,
+        O: MatrixExtFromIter<Self::Element>
+    {
+        window_extremum(self, h, w, |a, b| a >= b)
+    }
+
+    /// Resamples the matrix to `rows` rows and `cols` columns, taking the element nearest to the center of each new cell.
+    ///
+    /// # Panics
+    /// Panics if the matrix is empty while the requested shape is not.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = [
+    ///     [1, 2],
+    ///     [3, 4]
+    /// ];
+    ///
+    /// let up: [[i32; 4]; 3] = m.resize_nearest(3, 4);
+    /// assert_eq!([
+    ///     [1, 1, 2, 2],
+    ///     [3, 3, 4, 4],
+    ///     [3, 3, 4, 4]
+    /// ], up);
+    ///
+    /// let down: [[i32; 1]; 1] = up.resize_nearest(1, 1);
+    /// assert_eq!([[4]], down);
+    /// ```
+    fn resize_nearest<O>(&self, rows: usize, cols: usize) -> O
+    where
+        Self::Element: Clone,
+        O: MatrixExtFromIter<Self::Element>
+    {
+        let (r, c) = self.shape();
+        if rows * cols != 0 && r * c == 0 {
+            panic!("Cannot resize an empty matrix.")
+        }
+        let mut v = Vec::with_capacity(rows * cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                let (si, sj) = ((2 * i + 1) * r / (2 * rows), (2 * j + 1) * c / (2 * cols));
+                v.push(self.get(si, sj).unwrap().clone());
+            }
+        }
+        O::from_iter(v, cols)
+    }
+
+    /// Resamples the matrix to `rows` rows and `cols` columns using bilinear interpolation.
+    ///
+    /// Corner elements are kept at the corners of the output and other elements are interpolated between them.
+    ///
+    /// # Panics
+    /// Panics if the matrix is empty while the requested shape is not.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = [
+    ///     [0.0, 2.0],
+    ///     [4.0, 6.0]
+    /// ];
+    ///
+    /// let up: [[f64; 3]; 3] = m.resize_bilinear(3, 3);
+    /// assert_eq!([
+    ///     [0.0, 1.0, 2.0],
+    ///     [2.0, 3.0, 4.0],
+    ///     [4.0, 5.0, 6.0]
+    /// ], up);
+    /// ```
+    fn resize_bilinear<O>(&self, rows: usize, cols: usize) -> O
+    where
+        Self::Element: Float,
+        O: MatrixExtFromIter<Self::Element>
+    {
+        let (r, c) = self.shape();
+        if rows * cols != 0 && r * c == 0 {
+            panic!("Cannot resize an empty matrix.")
+        }
+
+        // Position of the `k`-th of `to` cells among `from` cells: index of the cell before it and the fraction left.
+        let locate = |k: usize, from: usize, to: usize| -> (usize, usize, Self::Element) {
+            if to <= 1 || from == 1 {
+                return (0, 0, Self::Element::ZERO)
+            }
+            let (num, den) = (k * (from - 1), to - 1);
+            let k0 = num / den;
+            let frac = Self::Element::from_usize(num % den) / Self::Element::from_usize(den);
+            (k0, (k0 + 1).min(from - 1), frac)
+        };
+
+        let mut v = Vec::with_capacity(rows * cols);
+        for i in 0..rows {
+            let (i0, i1, di) = locate(i, r, rows);
+            for j in 0..cols {
+                let (j0, j1, dj) = locate(j, c, cols);
+                let at = |i, j| *self.get(i, j).unwrap();
+                let top = at(i0, j0) + (at(i0, j1) - at(i0, j0)) * dj;
+                let bottom = at(i1, j0) + (at(i1, j1) - at(i1, j0)) * dj;
+                v.push(top + (bottom - top) * di);
+            }
+        }
+        O::from_iter(v, cols)
+    }
+
+    /// Returns the mean of the elements of the matrix, or `None` if it is empty.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// assert_eq!(Some(2.5), [[1.0, 2.0], [3.0, 4.0]].mean());
+    /// assert_eq!(None, ([] as [[f64; 2]; 0]).mean());
+    /// ```
+    fn mean(&self) -> Option<Self::Element>
+    where
+        Self: Sized,
+        Self::Element: Float
+    {
+        let n = self.size();
+        if n == 0 {
+            return None
+        }
+        Some(self.iter().fold(Self::Element::ZERO, |acc, &x| acc + x) / Self::Element::from_usize(n))
+    }
+
+    /// Returns the (population) variance of the elements of the matrix, or `None` if it is empty.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// assert_eq!(Some(1.25), [[1.0, 2.0], [3.0, 4.0]].variance());
+    /// ```
+    fn variance(&self) -> Option<Self::Element>
+    where
+        Self: Sized,
+        Self::Element: Float
+    {
+        let mean = self.mean()?;
+        let n = Self::Element::from_usize(self.size());
+        Some(self.iter().fold(Self::Element::ZERO, |acc, &x| acc + (x - mean) * (x - mean)) / n)
+    }
+
+    /// Returns the (population) standard deviation of the elements of the matrix, or `None` if it is empty.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// assert_eq!(Some(2.0), [[2.0, 4.0, 4.0, 4.0], [5.0, 5.0, 7.0, 9.0]].std_dev());
+    /// ```
+    fn std_dev(&self) -> Option<Self::Element>
+    where
+        Self: Sized,
+        Self::Element: Float
+    {
+        self.variance().map(Float::sqrt)
+    }
+
+    /// Returns the mean of each row.
+    ///
+    /// Rows of an empty matrix with no column have a mean of `NaN`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = [
+    ///     [1.0, 2.0, 3.0],
+    ///     [4.0, 6.0, 8.0]
+    /// ];
+    ///
+    /// assert_eq!(vec![2.0, 6.0], m.row_means());
+    /// ```
+    fn row_means(&self) -> Vec<Self::Element>
+    where
+        Self: Sized,
+        Self::Element: Float
+    {
+        let n = Self::Element::from_usize(self.row_len());
+        self.rows()
+            .map(|row| row.fold(Self::Element::ZERO, |acc, &x| acc + x) / n)
+            .collect()
+    }
+
+    /// Returns the mean of each column.
+    ///
+    /// Columns of an empty matrix with no row have a mean of `NaN`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = [
+    ///     [1.0, 2.0, 3.0],
+    ///     [4.0, 6.0, 8.0]
+    /// ];
+    ///
+    /// assert_eq!(vec![2.5, 4.0, 5.5], m.col_means());
+    /// ```
+    fn col_means(&self) -> Vec<Self::Element>
+    where
+        Self: Sized,
+        Self::Element: Float
+    {
+        let n = Self::Element::from_usize(self.col_len());
+        self.cols()
+            .map(|col| col.fold(Self::Element::ZERO, |acc, &x| acc + x) / n)
+            .collect()
+    }
+
+    /// Estimates the condition number of the matrix in the 1-norm (maximum absolute column sum).
+    ///
+    /// The norm of the inverse is estimated from an LU decomposition with Hager's method, without computing
+    /// the inverse. The estimate is a lower bound which is exact most of the time.
+    /// A large condition number means that solutions of linear systems involving this matrix are unreliable.
+    ///
+    /// Returns `None` if the matrix is empty, not square or singular.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = [
+    ///     [4.0, 1.0],
+    ///     [2.0, 3.0]
+    /// ];
+    /// // ‖m‖ = 6 and ‖m⁻¹‖ = 0.5
+    /// assert_eq!(Some(3.0), m.cond_1());
+    ///
+    /// let nearly_singular = [[1.0, 1.0], [1.0, 1.0 + 1e-10]];
+    /// assert!(nearly_singular.cond_1().unwrap() > 1e10);
+    ///
+    /// assert_eq!(None, [[1.0, 2.0], [2.0, 4.0]].cond_1());
+    /// ```
+    fn cond_1(&self) -> Option<Self::Element>
+    where
+        Self: Sized,
+        Self::Element: Float
+    {
+        condition_estimate(self, false)
+    }
+
+    /// Estimates the condition number of the matrix in the infinity norm (maximum absolute row sum).
+    ///
+    /// See [`cond_1`](#method.cond_1) for more details.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = [
+    ///     [4.0, 1.0],
+    ///     [2.0, 3.0]
+    /// ];
+    /// // ‖m‖ = 5 and ‖m⁻¹‖ = 0.6, but the estimate of ‖m⁻¹‖ is lower here.
+    /// assert_eq!(Some(8.0 / 3.0), m.cond_inf());
+    ///
+    /// let m = [
+    ///     [1.0, 2.0, 0.0],
+    ///     [0.0, 1.0, 0.0],
+    ///     [0.0, 0.0, 2.0]
+    /// ];
+    /// // ‖m‖ = 3 and ‖m⁻¹‖ = 3, found exactly.
+    /// assert_eq!(Some(9.0), m.cond_inf());
+    /// ```
+    fn cond_inf(&self) -> Option<Self::Element>
+    where
+        Self: Sized,
+        Self::Element: Float
+    {
+        condition_estimate(self, true)
+    }
+
+    /// Returns an object implementing [`Display`](::core::fmt::Display), printing the matrix one row per line
+    /// with its columns aligned.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = [
+    ///     [1, -20, 3],
+    ///     [400, 5, 6]
+    /// ];
+    ///
+    /// assert_eq!("  1 -20 3\n400   5 6", format!("{}", m.display()));
+    ///
+    /// let m = [[1.0, 0.5], [-2.75, 10.0]];
+    /// assert_eq!(" 1.0  0.5\n-2.8 10.0", format!("{:.1}", m.display()));
+    /// ```
+    #[inline]
+    fn display(&self) -> MatrixDisplay<'_, Self>
+    where
+        Self: Sized,
+        Self::Element: ::core::fmt::Display
+    {
+        MatrixDisplay::new(self)
+    }
+
+    /// Returns a random element of the matrix along with its subscripts, or `None` if the matrix is empty.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    /// use rand::{ SeedableRng, rngs::SmallRng };
+    ///
+    /// let m = [[1, 2], [3, 4]];
+    /// let mut rng = SmallRng::seed_from_u64(0);
+    ///
+    /// let (i, j, x) = m.choose(&mut rng).unwrap();
+    /// assert_eq!(m[i][j], *x);
+    ///
+    /// let empty: [[i32; 2]; 0] = [];
+    /// assert_eq!(None, empty.choose(&mut rng));
+    /// ```
+    #[cfg(feature = "rand")]
+    fn choose<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Option<(usize, usize, &Self::Element)> {
+        let size = self.size();
+        if size == 0 {
+            return None
+        }
+        let (i, j) = self.subscripts_from(rng.random_range(0..size));
+        Some((i, j, self.get(i, j)?))
+    }
+
+    /// Returns `k` distinct random elements of the matrix (or all of them if it has less than `k` elements)
+    /// along with their subscripts, in random order.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    /// use rand::{ SeedableRng, rngs::SmallRng };
+    ///
+    /// let m = [[1, 2, 3], [4, 5, 6]];
+    /// let mut rng = SmallRng::seed_from_u64(0);
+    ///
+    /// let mut sample = m.sample(4, &mut rng);
+    /// assert_eq!(4, sample.len());
+    /// assert!(sample.iter().all(|&(i, j, x)| m[i][j] == *x));
+    ///
+    /// sample.sort();
+    /// sample.dedup();
+    /// assert_eq!(4, sample.len());
+    ///
+    /// assert_eq!(6, m.sample(10, &mut rng).len());
+    /// ```
+    #[cfg(feature = "rand")]
+    fn sample<R: rand::Rng + ?Sized>(&self, k: usize, rng: &mut R) -> Vec<(usize, usize, &Self::Element)> {
+        let size = self.size();
+        rand::seq::index::sample(rng, size, k.min(size))
+            .into_iter()
+            .map(|n| {
+                let (i, j) = self.subscripts_from(n);
+                (i, j, self.get(i, j).unwrap())
+            })
+            .collect()
     }
 
     /// Returns the subscripts of the first element of the matrix that matches the condition.
     #[inline]
-    fn position2(&self, f: impl Fn(&Self::Element)->bool) -> Option<(usize, usize)> {
-        for i in 0..self.num_rows() {
-            for j in 0..self.num_cols() {
-                if f(self.get(i, j).unwrap()) {
-                   return Some((i, j))
-                }
-            }
-        }
-
-        None
+    fn position2(&self, f: impl Fn(&Self::Element)->bool) -> Option<(usize, usize)> {
+        for i in 0..self.num_rows() {
+            for j in 0..self.num_cols() {
+                if f(self.get(i, j).unwrap()) {
+                   return Some((i, j))
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Returns an iterator over the subscripts of the elements which differ from the element
+    /// at the same subscripts in `other`, in *Row Major Order*.
+    ///
+    /// Elements are compared lazily, as the iterator advances.
+    ///
+    /// # Panics
+    /// Panics if the matrices do not have the same shape.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// let before = [['.', '#', '.'], ['.', '.', '.']];
+    /// let after  = [['.', '.', '.'], ['#', '.', '#']];
+    ///
+    /// let changed: Vec<_> = before.positions_ne(&after).collect();
+    /// assert_eq!(vec![(0, 1), (1, 0), (1, 2)], changed);
+    ///
+    /// assert_eq!(None, before.positions_ne(&before).next());
+    /// ```
+    #[inline]
+    fn positions_ne<'a, M: MatrixExt>(&'a self, other: &'a M) -> PositionsNe<'a, Self, M>
+    where
+        Self: Sized,
+        Self::Element: PartialEq<M::Element>
+    {
+        PositionsNe::new(self, other)
     }
 
     /// Checks if the matrix is empty.
@@ -1129,6 +2553,58 @@ pub trait MatrixExt
 
         true
     }
+
+    /// Checks if a matrix of complex numbers is Hermitian i.e. it is square and equal to its
+    /// [conjugate transpose](MatrixExt::hermitian).
+    ///
+    /// Elements are compared in place, without building the conjugate transpose.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    /// use num_complex::Complex;
+    ///
+    /// let m = [
+    ///     [Complex::new(2, 0), Complex::new(1, -1)],
+    ///     [Complex::new(1, 1), Complex::new(3,  0)],
+    /// ];
+    /// assert!(m.is_hermitian());
+    ///
+    /// // Diagonal elements of a Hermitian matrix are real.
+    /// assert!(![[Complex::new(0, 1)]].is_hermitian());
+    ///
+    /// // Symmetric is not enough.
+    /// let s = [
+    ///     [Complex::new(2, 0), Complex::new(1, 1)],
+    ///     [Complex::new(1, 1), Complex::new(3, 0)],
+    /// ];
+    /// assert!(s.is_symmetric());
+    /// assert!(!s.is_hermitian());
+    ///
+    /// let empty: [[Complex<i32>; 0]; 0] = [];
+    /// assert!(!empty.is_hermitian());
+    /// ```
+    #[cfg(feature = "num-complex")]
+    fn is_hermitian<T>(&self) -> bool
+    where
+        Self: MatrixExt<Element = num_complex::Complex<T>>,
+        T: Clone + PartialEq + ::core::ops::Neg<Output = T>
+    {
+        if self.is_empty() || !self.is_square() {
+            return false
+        }
+
+        for i in 0..self.num_rows() {
+            for j in 0..=i {
+                let (a, b) = (self.get(i, j).unwrap(), self.get(j, i).unwrap());
+                if a.re != b.re || a.im != -b.im.clone() {
+                    return false
+                }
+            }
+        }
+
+        true
+    }
     
     /// Checks if the matrix is skew-symmetric (antisymmetric).
     /// 
@@ -1456,119 +2932,375 @@ pub trait MatrixMutExt: MatrixExt {
     ///
     /// let mut v = [[0, 1, 3, 3, 4, 5]];
     ///
-    /// assert_eq!(3, v[0][2]);
+    /// assert_eq!(3, v[0][2]);
+    ///
+    /// let n = v.get_nth_mut(2).unwrap();
+    /// *n = 2;
+    ///
+    /// assert_eq!(2, v[0][2]);
+    /// ```
+    #[inline]
+    fn get_nth_mut(&mut self, n: usize) -> Option<&mut Self::Element> {
+        let (i, j) = self.subscripts_from(n);
+        self.get_mut(i, j)
+    }
+    
+    
+    /// Returns mutable a reference to an element given its linear order, without doing bound checking.
+    ///
+    /// For a safe alternative see [`get_nth_mut`].
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is *[undefined behavior](https://doc.rust-lang.org/reference/behavior-considered-undefined.html)*
+    /// even if the resulting reference is not used.
+    ///
+    /// You can think of this like `.get_nth_mut(index).unwrap_unchecked()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixMutExt;
+    ///
+    /// let x = &mut [[1, 2, 4]];
+    ///
+    /// unsafe {
+    ///     let elem = x.get_nth_unchecked_mut(1);
+    ///        *elem = 13;
+    /// }
+    ///
+    /// assert_eq!(x, &[[1, 13, 4]]);
+    /// ```
+    /// [`get_nth_mut`]: crate::MatrixMutExt::get_nth_mut
+    unsafe fn get_nth_unchecked_mut(&mut self, n: usize) -> &mut Self::Element {
+        let (i, j) = self.subscripts_from(n);
+        self.get_unchecked_mut(i, j)
+    }
+    
+    #[inline]
+    /// Returns a mutable pointer to the first element of the  matrix, or `None` if it is empty.
+    fn first_mut(&mut self) -> Option<&mut Self::Element> {
+        self.get_mut(0, 0)
+    }
+    
+    #[inline]
+    /// Returns a mutable pointer to the last element of the  matrix, or `None` if it is empty.
+    fn last_mut(&mut self) -> Option<&mut Self::Element> {
+        match self.shape() {
+            (0, _) | (_, 0) => None,
+            (r, c) => self.get_mut(r - 1, c - 1)
+        }
+    }
+    
+    
+    /// Changes the value of an element at the intersection of the `i`-th row and the `j`-th column of the matrix.
+    ///
+    /// # Error
+    /// An error is returned if any of those indexes are out of bounds.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::{MatrixExt, MatrixMutExt}; 
+    /// 
+    /// let mut m = [[1, 2, 3]];
+    ///
+    /// assert_eq!(Ok(()), m.set((0, 2), 100));
+    /// assert_eq!(Some(&100), m.get(0, 2));
+    ///
+    /// assert_eq!(Err("Cannot access element from indexes."), m.set((1, 0), 11));
+    /// ```
+    #[inline]
+    fn set(&mut self, subscripts: (usize, usize), val: Self::Element) -> Result<(), &'static str> {
+        match self.get_mut(subscripts.0, subscripts.1) {
+            Some(target) => {
+                *target = val;
+                Ok(())
+            }
+            None => Err("Cannot access element from indexes."),
+        }
+    }   
+    
+    /// Changes the value of the `n`-th element of the matrix.
+    ///
+    /// # Error
+    /// An error is returned if `n` is out of bound.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::{MatrixExt, MatrixMutExt}; 
+    /// 
+    /// let mut m = [[1, 2, 3]];
+    ///
+    /// assert_eq!(Ok(()), m.set_nth(2, 100));
+    /// assert_eq!(Some(&100), m.get(0, 2));
+    ///
+    /// assert_eq!(Err("Cannot access element from index."), m.set_nth(3, 11));
+    /// ```
+    #[inline]
+    fn set_nth(&mut self, n: usize, val: Self::Element) -> Result<(), &'static str> {
+        let (i, j) = self.subscripts_from(n);
+        match self.get_mut(i, j) {
+            Some(target) => {
+                *target = val;
+                Ok(())
+            }
+            None => Err("Cannot access element from index."),
+        }
+    }
+    
+    /// Applies a function to the element at the intersection of the `i`-th row and the `j`-th column of the matrix.
+    ///
+    /// # Error
+    /// An error is returned if any of those indexes are out of bounds.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::{MatrixExt, MatrixMutExt}; 
+    /// 
+    /// let mut m = [[1, 2, 3]];
+    ///
+    /// assert_eq!(Ok(()), m.modify((0, 2), |x| *x += 10));
+    /// assert_eq!(Some(&13), m.get(0, 2));
+    ///
+    /// assert_eq!(Err("Cannot access element from indexes."), m.modify((1, 0), |x| *x += 10));
+    /// ```
+    #[inline]
+    fn modify(&mut self, subscripts: (usize, usize), f: impl FnOnce(&mut Self::Element)) -> Result<(), &'static str> {
+        match self.get_mut(subscripts.0, subscripts.1) {
+            Some(target) => {
+                f(target);
+                Ok(())
+            }
+            None => Err("Cannot access element from indexes."),
+        }
+    }
+
+    /// Applies a function to the `n`-th element of the matrix.
+    ///
+    /// # Error
+    /// An error is returned if `n` is out of bound.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::{MatrixExt, MatrixMutExt}; 
+    /// 
+    /// let mut m = [[1, 2, 3]];
+    ///
+    /// assert_eq!(Ok(()), m.update_nth(2, |x| *x *= 10));
+    /// assert_eq!(Some(&30), m.get(0, 2));
+    ///
+    /// assert_eq!(Err("Cannot access element from index."), m.update_nth(3, |x| *x *= 10));
+    /// ```
+    #[inline]
+    fn update_nth(&mut self, n: usize, f: impl FnOnce(&mut Self::Element)) -> Result<(), &'static str> {
+        match self.get_nth_mut(n) {
+            Some(target) => {
+                f(target);
+                Ok(())
+            }
+            None => Err("Cannot access element from index."),
+        }
+    }
+
+    /// Applies a function to every element of the matrix, in *Row Major Order*.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixMutExt;
+    ///
+    /// let mut m = [[1, 2], [3, 4]];
+    ///
+    /// m.apply(|x| *x *= *x);
+    ///
+    /// assert_eq!([[1, 4], [9, 16]], m);
+    /// ```
+    #[inline]
+    fn apply(&mut self, f: impl FnMut(&mut Self::Element))
+    where Self: Sized
+    {
+        self.iter_mut().for_each(f)
+    }
+
+    /// Applies a function to every element of the matrix along with its subscripts, in *Row Major Order*.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixMutExt;
+    ///
+    /// let mut m = [[0; 3]; 2];
+    ///
+    /// m.apply_indexed(|i, j, x| *x = 10 * i + j);
+    ///
+    /// assert_eq!([[0, 1, 2], [10, 11, 12]], m);
+    /// ```
+    #[inline]
+    fn apply_indexed(&mut self, mut f: impl FnMut(usize, usize, &mut Self::Element))
+    where Self: Sized
+    {
+        self.enumerate_mut().for_each(|(i, j, x)| f(i, j, x))
+    }
+
+    /// Sets every element matching the condition to `value`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixMutExt;
+    ///
+    /// let mut m = [[12, 200, 45], [255, 3, 130]];
+    ///
+    /// // Thresholding.
+    /// m.set_where(|&x| x < 128, 0);
+    /// m.set_where(|&x| x >= 128, 255);
+    ///
+    /// assert_eq!([[0, 255, 0], [255, 0, 255]], m);
+    /// ```
+    fn set_where(&mut self, mut f: impl FnMut(&Self::Element) -> bool, value: Self::Element)
+    where
+        Self: Sized,
+        Self::Element: Clone
+    {
+        self.iter_mut().filter(|x| f(x)).for_each(|x| x.clone_from(&value));
+    }
+
+    /// Calls `f` with the subscripts of every element and the element itself, and sets the element
+    /// to the value returned if there is one.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixMutExt;
+    ///
+    /// let mut m = [[0.2, 0.9], [0.6, 0.1]];
+    ///
+    /// // Classification, leaving the first column unchanged.
+    /// m.set_where_indexed(|_, j, &x| (j > 0).then(|| if x > 0.5 { 1.0 } else { 0.0 }));
+    ///
+    /// assert_eq!([[0.2, 1.0], [0.6, 0.0]], m);
+    /// ```
+    fn set_where_indexed(&mut self, mut f: impl FnMut(usize, usize, &Self::Element) -> Option<Self::Element>)
+    where Self: Sized
+    {
+        self.apply_indexed(|i, j, x| {
+            if let Some(value) = f(i, j, x) {
+                *x = value;
+            }
+        });
+    }
+
+    /// Sets every element of the rectangle of `shape` (rows, columns) starting at `top_left` to `value`.
+    ///
+    /// The parts of the rectangle outside of the matrix are ignored.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixMutExt;
+    ///
+    /// let mut m = [['.'; 4]; 3];
     ///
-    /// let n = v.get_nth_mut(2).unwrap();
-    /// *n = 2;
+    /// m.fill_region((1, 1), (2, 2), '#');
+    /// m.fill_region((0, 3), (5, 5), '|');
     ///
-    /// assert_eq!(2, v[0][2]);
+    /// assert_eq!([
+    ///     ['.', '.', '.', '|'],
+    ///     ['.', '#', '#', '|'],
+    ///     ['.', '#', '#', '|']
+    /// ], m);
     /// ```
     #[inline]
-    fn get_nth_mut(&mut self, n: usize) -> Option<&mut Self::Element> {
-        let (i, j) = self.subscripts_from(n);
-        self.get_mut(i, j)
+    fn fill_region(&mut self, top_left: (usize, usize), shape: (usize, usize), value: Self::Element)
+    where Self::Element: Clone
+    {
+        self.fill_region_with(top_left, shape, |_, _| value.clone())
     }
-    
-    
-    /// Returns mutable a reference to an element given its linear order, without doing bound checking.
-    ///
-    /// For a safe alternative see [`get_nth_mut`].
-    ///
-    /// # Safety
-    ///
-    /// Calling this method with an out-of-bounds index is *[undefined behavior](https://doc.rust-lang.org/reference/behavior-considered-undefined.html)*
-    /// even if the resulting reference is not used.
+
+    /// Sets every element of the rectangle of `shape` (rows, columns) starting at `top_left`
+    /// to the value returned by `f` for its subscripts.
     ///
-    /// You can think of this like `.get_nth_mut(index).unwrap_unchecked()`.
+    /// The parts of the rectangle outside of the matrix are ignored.
     ///
     /// # Example
     /// ```rust
     /// use matrixable::MatrixMutExt;
     ///
-    /// let x = &mut [[1, 2, 4]];
+    /// let mut m = [[0; 3]; 3];
     ///
-    /// unsafe {
-    ///     let elem = x.get_nth_unchecked_mut(1);
-    ///        *elem = 13;
-    /// }
+    /// m.fill_region_with((1, 0), (2, 3), |i, j| 10 * i + j);
     ///
-    /// assert_eq!(x, &[[1, 13, 4]]);
+    /// assert_eq!([
+    ///     [ 0,  0,  0],
+    ///     [10, 11, 12],
+    ///     [20, 21, 22]
+    /// ], m);
     /// ```
-    /// [`get_nth_mut`]: crate::MatrixMutExt::get_nth_mut
-    unsafe fn get_nth_unchecked_mut(&mut self, n: usize) -> &mut Self::Element {
-        let (i, j) = self.subscripts_from(n);
-        self.get_unchecked_mut(i, j)
-    }
-    
-    #[inline]
-    /// Returns a mutable pointer to the first element of the  matrix, or `None` if it is empty.
-    fn first_mut(&mut self) -> Option<&mut Self::Element> {
-        self.get_mut(0, 0)
-    }
-    
-    #[inline]
-    /// Returns a mutable pointer to the last element of the  matrix, or `None` if it is empty.
-    fn last_mut(&mut self) -> Option<&mut Self::Element> {
-        match self.shape() {
-            (0, _) | (_, 0) => None,
-            (r, c) => self.get_mut(r - 1, c - 1)
+    fn fill_region_with(&mut self, top_left: (usize, usize), shape: (usize, usize), mut f: impl FnMut(usize, usize) -> Self::Element) {
+        let (r0, c0) = top_left;
+        let r1 = r0.saturating_add(shape.0).min(self.num_rows());
+        let c1 = c0.saturating_add(shape.1).min(self.num_cols());
+        for i in r0..r1 {
+            for j in c0..c1 {
+                *self.get_mut(i, j).unwrap() = f(i, j);
+            }
         }
     }
-    
-    
-    /// Changes the value of an element at the intersection of the `i`-th row and the `j`-th column of the matrix.
+
+    /// Sets the outline of the rectangle of `shape` (rows, columns) starting at `top_left` to `value`,
+    /// leaving its inside unchanged.
     ///
-    /// # Error
-    /// An error is returned if any of those indexes are out of bounds.
+    /// The parts of the rectangle outside of the matrix are ignored.
     ///
     /// # Example
     /// ```rust
-    /// use matrixable::{MatrixExt, MatrixMutExt}; 
-    /// 
-    /// let mut m = [[1, 2, 3]];
+    /// use matrixable::MatrixMutExt;
     ///
-    /// assert_eq!(Ok(()), m.set((0, 2), 100));
-    /// assert_eq!(Some(&100), m.get(0, 2));
+    /// let mut m = [['.'; 5]; 4];
     ///
-    /// assert_eq!(Err("Cannot access element from indexes."), m.set((1, 0), 11));
+    /// m.draw_rect((0, 1), (3, 3), '#');
+    /// m.draw_rect((2, 3), (4, 4), '+');
+    ///
+    /// assert_eq!([
+    ///     ['.', '#', '#', '#', '.'],
+    ///     ['.', '#', '.', '#', '.'],
+    ///     ['.', '#', '#', '+', '+'],
+    ///     ['.', '.', '.', '+', '.']
+    /// ], m);
     /// ```
-    #[inline]
-    fn set(&mut self, subscripts: (usize, usize), val: Self::Element) -> Result<(), &'static str> {
-        match self.get_mut(subscripts.0, subscripts.1) {
-            Some(target) => {
-                *target = val;
-                Ok(())
-            }
-            None => Err("Cannot access element from indexes."),
+    fn draw_rect(&mut self, top_left: (usize, usize), shape: (usize, usize), value: Self::Element)
+    where Self::Element: Clone
+    {
+        let (rows, cols) = shape;
+        if rows == 0 || cols == 0 {
+            return
         }
-    }   
-    
-    /// Changes the value of the `n`-th element of the matrix.
-    ///
-    /// # Error
-    /// An error is returned if `n` is out of bound.
+        let (r0, c0) = top_left;
+        let (r1, c1) = (r0.saturating_add(rows - 1), c0.saturating_add(cols - 1));
+        for r in [r0, r1] {
+            self.fill_region((r, c0), (1, cols), value.clone());
+        }
+        for c in [c0, c1] {
+            self.fill_region((r0, c), (rows, 1), value.clone());
+        }
+    }
+
+    /// Sets the elements on the edges of the matrix to `value`.
     ///
     /// # Example
     /// ```rust
-    /// use matrixable::{MatrixExt, MatrixMutExt}; 
-    /// 
-    /// let mut m = [[1, 2, 3]];
+    /// use matrixable::MatrixMutExt;
     ///
-    /// assert_eq!(Ok(()), m.set_nth(2, 100));
-    /// assert_eq!(Some(&100), m.get(0, 2));
+    /// let mut m = [[0; 4]; 3];
     ///
-    /// assert_eq!(Err("Cannot access element from index."), m.set_nth(3, 11));
+    /// m.draw_border(1);
+    ///
+    /// assert_eq!([
+    ///     [1, 1, 1, 1],
+    ///     [1, 0, 0, 1],
+    ///     [1, 1, 1, 1]
+    /// ], m);
     /// ```
     #[inline]
-    fn set_nth(&mut self, n: usize, val: Self::Element) -> Result<(), &'static str> {
-        let (i, j) = self.subscripts_from(n);
-        match self.get_mut(i, j) {
-            Some(target) => {
-                *target = val;
-                Ok(())
-            }
-            None => Err("Cannot access element from index."),
-        }
+    fn draw_border(&mut self, value: Self::Element)
+    where Self::Element: Clone
+    {
+        let shape = self.shape();
+        self.draw_rect((0, 0), shape, value)
     }
     
     /// Swaps two elements in the matrix identified by their subscripts.
@@ -1627,48 +3359,273 @@ pub trait MatrixMutExt: MatrixExt {
     /// ```rust
     /// use matrixable::MatrixMutExt;
     ///
-    /// let mut m = [
-    ///     [(0,0), (0,1), (0,2)],
-    ///     [(1,0), (1,1), (1,2)],
-    ///     [(2,0), (2,1), (2,2)]
-    /// ];
+    /// let mut m = [
+    ///     [(0,0), (0,1), (0,2)],
+    ///     [(1,0), (1,1), (1,2)],
+    ///     [(2,0), (2,1), (2,2)]
+    /// ];
+    ///
+    /// m.swapn(2, 7);
+    /// 
+    /// let expected = [
+    ///         [(0,0), (0,1), (2,1)],
+    ///         [(1,0), (1,1), (1,2)],
+    ///         [(2,0), (0,2), (2,2)]
+    /// ];
+    ///
+    /// assert_eq!(expected, m);
+    /// ```
+    fn swapn(&mut self, a: usize, b: usize) {
+        if a == b { return }
+        let a: *mut Self::Element = self.get_nth_mut(a).unwrap();
+        let b: *mut Self::Element = self.get_nth_mut(b).unwrap();
+        unsafe { ::core::ptr::swap(&mut *a, &mut *b) };
+    }
+
+    /// Swaps two columns.
+    /// # Panics
+    /// Panics if a column index is out of bound.
+    #[inline]
+    fn swap_cols(&mut self, col1: usize, col2: usize) {
+        for i in 0..self.num_rows() {
+            self.swap((i, col1), (i, col2));
+        }
+    }
+
+
+    /// Swaps two rows.
+    /// # Panics
+    /// Panics if a row index is out of bound.
+    #[inline]
+    fn swap_rows(&mut self, row1: usize, row2: usize) {
+        for j in 0..self.num_cols() {
+            self.swap((row1, j), (row2, j));
+        }
+    }
+
+    /// Swaps the `i`-th row of this matrix with the `k`-th row of another matrix.
+    ///
+    /// # Panics
+    /// Panics if a row index is out of bound or if both matrices do not have the same number of columns.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixMutExt;
+    ///
+    /// let mut a = [[1, 2], [3, 4]];
+    /// let mut b = [[5, 6], [7, 8], [9, 10]];
+    ///
+    /// a.swap_row_with(0, &mut b, 2);
+    ///
+    /// assert_eq!([[9, 10], [3, 4]], a);
+    /// assert_eq!([[5, 6], [7, 8], [1, 2]], b);
+    /// ```
+    fn swap_row_with<M>(&mut self, i: usize, other: &mut M, k: usize)
+    where M: MatrixMutExt<Element = Self::Element>
+    {
+        if self.num_cols() != other.num_cols() {
+            panic!("Cannot swap rows of different lengths.")
+        }
+        for j in 0..self.num_cols() {
+            ::core::mem::swap(self.get_mut(i, j).unwrap(), other.get_mut(k, j).unwrap());
+        }
+    }
+
+    /// Swaps the `j`-th column of this matrix with the `k`-th column of another matrix.
+    ///
+    /// # Panics
+    /// Panics if a column index is out of bound or if both matrices do not have the same number of rows.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixMutExt;
+    ///
+    /// let mut a = [[1, 2], [3, 4]];
+    /// let mut b = [[5, 6, 7], [8, 9, 10]];
+    ///
+    /// a.swap_col_with(1, &mut b, 0);
+    ///
+    /// assert_eq!([[1, 5], [3, 8]], a);
+    /// assert_eq!([[2, 6, 7], [4, 9, 10]], b);
+    /// ```
+    fn swap_col_with<M>(&mut self, j: usize, other: &mut M, k: usize)
+    where M: MatrixMutExt<Element = Self::Element>
+    {
+        if self.num_rows() != other.num_rows() {
+            panic!("Cannot swap columns of different lengths.")
+        }
+        for i in 0..self.num_rows() {
+            ::core::mem::swap(self.get_mut(i, j).unwrap(), other.get_mut(i, k).unwrap());
+        }
+    }
+
+    /// Sorts the rows of the matrix according to their value in the `col`-th column.
+    ///
+    /// If `before` returns true, then its first parameter is considered to come before the second parameter.
+    /// Rows are moved as a whole (at most *rows* - 1 calls to [`swap_rows`](#method.swap_rows)) and rows
+    /// having equal keys keep their relative order.
+    ///
+    /// For more complex orderings, see the [`SortRowsBy`](crate::strategies::SortRowsBy) strategy.
+    ///
+    /// # Panics
+    /// Panics if `col` is out of bound.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixMutExt;
+    ///
+    /// let mut m = [
+    ///     ['b', 'x', 'z'],
+    ///     ['c', 'y', 'x'],
+    ///     ['a', 'z', 'y'],
+    /// ];
+    ///
+    /// m.sort_rows_by_key(2, |a, b| a < b);
+    ///
+    /// assert_eq!([
+    ///     ['c', 'y', 'x'],
+    ///     ['a', 'z', 'y'],
+    ///     ['b', 'x', 'z'],
+    /// ], m);
+    /// ```
+    fn sort_rows_by_key(&mut self, col: usize, before: impl Fn(&Self::Element, &Self::Element) -> bool)
+    where Self: Sized
+    {
+        if col >= self.num_cols() {
+            panic!("Column index out of bound.")
+        }
+        let order = {
+            let m: &Self = self;
+            crate::strategies::sorted_order(m.num_rows(), |a, b| before(m.get(a, col).unwrap(), m.get(b, col).unwrap()))
+        };
+        crate::strategies::apply_order(&order, |a, b| self.swap_rows(a, b));
+    }
+
+    /// Linearly maps elements from the range `from` to the range `to`.
+    ///
+    /// Elements outside of `from` are mapped outside of `to` the same way.
+    /// If `from` contains a single value, all the elements are set to the start of `to`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixMutExt;
+    ///
+    /// let mut m = [[0.0, 5.0], [10.0, 20.0]];
+    ///
+    /// m.rescale(0.0..=10.0, -1.0..=1.0);
+    ///
+    /// assert_eq!([[-1.0, 0.0], [1.0, 3.0]], m);
+    /// ```
+    fn rescale(&mut self, from: ::core::ops::RangeInclusive<Self::Element>, to: ::core::ops::RangeInclusive<Self::Element>)
+    where
+        Self: Sized,
+        Self::Element: Float
+    {
+        let (a, b) = from.into_inner();
+        let (c, d) = to.into_inner();
+        if a == b {
+            self.iter_mut().for_each(|x| *x = c);
+            return
+        }
+        let ratio = (d - c) / (b - a);
+        self.iter_mut().for_each(|x| *x = c + (*x - a) * ratio);
+    }
+
+    /// Restricts every element to the interval `[min, max]`: elements below `min` are set to `min`
+    /// and elements above `max` are set to `max`.
+    ///
+    /// Elements which cannot be compared with the bounds (like `NaN`) are left unchanged.
+    ///
+    /// # Panics
+    /// Panics if `min > max`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixMutExt;
+    ///
+    /// let mut m = [[-3, 0, 7], [12, 255, 4]];
+    ///
+    /// m.clamp_elements(0, 10);
+    ///
+    /// assert_eq!([[0, 0, 7], [10, 10, 4]], m);
+    /// ```
+    fn clamp_elements(&mut self, min: Self::Element, max: Self::Element)
+    where
+        Self: Sized,
+        Self::Element: PartialOrd + Clone
+    {
+        if min > max {
+            panic!("Cannot clamp elements when min is greater than max.")
+        }
+        self.iter_mut().for_each(|x| {
+            if *x < min {
+                x.clone_from(&min)
+            }
+            else if *x > max {
+                x.clone_from(&max)
+            }
+        });
+    }
+
+    /// Linearly maps elements so that the minimum becomes `0` and the maximum becomes `1`.
+    ///
+    /// All elements are set to `0` if they are equal. Nothing is done if the matrix is empty.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixMutExt;
+    ///
+    /// let mut m = [[2.0, 4.0], [6.0, 10.0]];
+    ///
+    /// m.normalize_minmax();
+    ///
+    /// assert_eq!([[0.0, 0.25], [0.5, 1.0]], m);
+    /// ```
+    fn normalize_minmax(&mut self)
+    where
+        Self: Sized,
+        Self::Element: Float
+    {
+        let mut iter = self.iter().copied();
+        let first = match iter.next() {
+            Some(x) => x,
+            None => return,
+        };
+        let (min, max) = iter.fold((first, first), |(min, max), x| {
+            (if x < min { x } else { min }, if x > max { x } else { max })
+        });
+        self.rescale(min..=max, Self::Element::ZERO..=Self::Element::ONE);
+    }
+
+    /// Centers the elements on their mean and divides them by their (population) standard deviation.
+    ///
+    /// All elements are set to `0` if they are equal. Nothing is done if the matrix is empty.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixMutExt;
+    ///
+    /// let mut m = [[1.0, 3.0], [5.0, 7.0]];
     ///
-    /// m.swapn(2, 7);
-    /// 
-    /// let expected = [
-    ///         [(0,0), (0,1), (2,1)],
-    ///         [(1,0), (1,1), (1,2)],
-    ///         [(2,0), (0,2), (2,2)]
-    /// ];
+    /// m.normalize_zscore();
     ///
-    /// assert_eq!(expected, m);
+    /// let s = 5f64.sqrt();
+    /// assert_eq!([[-3.0 / s, -1.0 / s], [1.0 / s, 3.0 / s]], m);
     /// ```
-    fn swapn(&mut self, a: usize, b: usize) {
-        if a == b { return }
-        let a: *mut Self::Element = self.get_nth_mut(a).unwrap();
-        let b: *mut Self::Element = self.get_nth_mut(b).unwrap();
-        unsafe { ::core::ptr::swap(&mut *a, &mut *b) };
-    }
-
-    /// Swaps two columns.
-    /// # Panics
-    /// Panics if a column index is out of bound.
-    #[inline]
-    fn swap_cols(&mut self, col1: usize, col2: usize) {
-        for i in 0..self.num_rows() {
-            self.swap((i, col1), (i, col2));
-        }
-    }
-
-
-    /// Swaps two rows.
-    /// # Panics
-    /// Panics if a row index is out of bound.
-    #[inline]
-    fn swap_rows(&mut self, row1: usize, row2: usize) {
-        for j in 0..self.num_cols() {
-            self.swap((row1, j), (row2, j));
+    fn normalize_zscore(&mut self)
+    where
+        Self: Sized,
+        Self::Element: Float
+    {
+        let (mean, std) = match (self.mean(), self.std_dev()) {
+            (Some(mean), Some(std)) => (mean, std),
+            _ => return,
+        };
+        if std == Self::Element::ZERO {
+            self.iter_mut().for_each(|x| *x = Self::Element::ZERO);
+            return
         }
+        self.iter_mut().for_each(|x| *x = (*x - mean) / std);
     }
 
     /// Returns an iterator that allows modifying each element.
@@ -1720,6 +3677,12 @@ pub trait MatrixMutExt: MatrixExt {
     }
     
     /// Returns an iterator over the mutable elements of the `i`-th row, without doing bound checking.
+    ///
+    /// For a safe alternative see [`row_mut`](#method.row_mut).
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is *[undefined behavior](https://doc.rust-lang.org/reference/behavior-considered-undefined.html)*.
     unsafe fn row_unchecked_mut(&mut self, i: usize) -> RowMut<'_, Self> 
     where Self: Sized {
         self.row_mut(i).unwrap_unchecked()
@@ -1754,6 +3717,12 @@ pub trait MatrixMutExt: MatrixExt {
     } 
     
     /// Returns an iterator over the mutable elements of the `j`-th column, without doing bound checking.
+    ///
+    /// For a safe alternative see [`col_mut`](#method.col_mut).
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is *[undefined behavior](https://doc.rust-lang.org/reference/behavior-considered-undefined.html)*.
     unsafe fn col_unchecked_mut(&mut self, j: usize) -> ColumnMut<'_, Self>
     where Self: Sized {
         self.col_mut(j).unwrap_unchecked()
@@ -1796,10 +3765,54 @@ pub trait MatrixMutExt: MatrixExt {
     }
     
     /// Returns an iterator over the mutable elements of the `n`-th diagonal, without doing bound checking.
+    ///
+    /// For a safe alternative see [`diag_mut`](#method.diag_mut).
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is *[undefined behavior](https://doc.rust-lang.org/reference/behavior-considered-undefined.html)*.
     unsafe fn diag_unchecked_mut(&mut self, n: usize) -> DiagMut<'_, Self>
     where Self: Sized {
         self.diag_mut(n).unwrap_unchecked()
     }
+
+    /// Returns an iterator over the mutable elements of the `n`-th anti-diagonal, from top-right to bottom-left.
+    ///
+    /// See [`antidiag`](MatrixExt::antidiag) for how anti-diagonals are numbered.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixMutExt;
+    ///
+    /// let mut m = [
+    ///     [0, 0, 0],
+    ///     [0, 0, 0],
+    ///     [0, 0, 0],
+    /// ];
+    ///
+    /// for (k, elem) in m.antidiag_mut(2).unwrap().enumerate() {
+    ///     *elem = k + 1;
+    /// }
+    /// 
+    /// assert_eq!([
+    ///     [0, 0, 1],
+    ///     [0, 2, 0],
+    ///     [3, 0, 0],
+    /// ], m);
+    ///
+    /// assert!(m.antidiag_mut(5).is_none());
+    /// ```
+    #[inline]
+    fn antidiag_mut(&mut self, n: usize) -> Option<AntiDiagMut<'_, Self>>
+    where Self: Sized
+    {
+        if n >= self.num_antidiags() {
+            None
+        }
+        else {
+            Some(AntiDiagMut::new(self, n))
+        }
+    }
     
     /// Returns the main diagonal (mutable).
     //
@@ -1829,7 +3842,140 @@ pub trait MatrixMutExt: MatrixExt {
         let n = self.num_rows();
         DiagMut::new(self, n.saturating_sub(1))
     }
+
+    /// Returns the main anti-diagonal (mutable).
+    //
+    /// # Example
+    /// ```rust    
+    /// use matrixable::MatrixMutExt;
+    ///
+    /// let m = &mut [
+    ///     [0, 0],
+    ///     [0, 0],
+    ///     [0, 0]
+    /// ];
+    ///     
+    /// for elem in m.main_antidiag_mut() {    
+    ///     *elem = 1;
+    /// }
+    /// 
+    /// assert_eq!(&mut [
+    ///     [0, 1],
+    ///     [1, 0],
+    ///     [0, 0],
+    /// ], m);
+    /// ```
+    fn main_antidiag_mut(&mut self) -> AntiDiagMut<'_, Self> 
+    where Self: Sized {
+        let n = self.num_cols();
+        AntiDiagMut::new(self, n.saturating_sub(1))
+    }
+
+    /// Returns an iterator over the mutable elements of the diagonal at offset `k` from the main diagonal.
+    ///
+    /// See [`diag_offset`](crate::MatrixExt::diag_offset) for the numbering of diagonals.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixMutExt;
+    ///
+    /// let mut m = [[0; 3]; 3];
+    ///
+    /// m.diag_offset_mut(1).unwrap().for_each(|x| *x = 1);
+    /// m.diag_offset_mut(-1).unwrap().for_each(|x| *x = -1);
+    ///
+    /// assert_eq!([
+    ///     [ 0,  1,  0],
+    ///     [-1,  0,  1],
+    ///     [ 0, -1,  0]
+    /// ], m);
+    /// ```
+    #[inline]
+    fn diag_offset_mut(&mut self, k: isize) -> Option<DiagMut<'_, Self>>
+    where Self: Sized
+    {
+        let n = (self.num_rows() as isize - 1).checked_add(k)?;
+        self.diag_mut(usize::try_from(n).ok()?)
+    }
     
+    /// Returns an iterator over groups of `n` consecutive rows (mutable), each of them seen as a matrix.
+    ///
+    /// The last group has less than `n` rows if `n` does not divide the number of rows.
+    /// Groups are disjoint parts of the storage of the matrix, so they can be handed to different threads.
+    /// This is why this method is only available for matrices with a contiguous storage ([`MatrixSlicesMut`]).
+    ///
+    /// # Panics
+    /// Panics if `n` is 0.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixMutExt;
+    ///
+    /// let mut m = [[0; 2]; 5];
+    ///
+    /// std::thread::scope(|s| {
+    ///     for (k, mut chunk) in m.rows_chunks_mut(2).enumerate() {
+    ///         s.spawn(move || chunk.iter_mut().for_each(|x| *x = k));
+    ///     }
+    /// });
+    ///
+    /// assert_eq!([[0, 0], [0, 0], [1, 1], [1, 1], [2, 2]], m);
+    /// ```
+    fn rows_chunks_mut(&mut self, n: usize) -> RowsChunksMut<'_, Self::Element>
+    where Self: Sized + MatrixSlicesMut
+    {
+        if n == 0 {
+            panic!("Chunk size must be greater than zero.")
+        }
+        let shape = self.shape();
+        RowsChunksMut::new(self.as_flat_slice_mut(), shape, n)
+    }
+
+    /// Returns an iterator over the black cells of the matrix seen as a checkerboard (mutable).
+    ///
+    /// See [`black_cells`](MatrixExt::black_cells).
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixMutExt;
+    ///
+    /// let mut m = [[0; 4]; 3];
+    ///
+    /// m.black_cells_mut().for_each(|x| *x = 1);
+    /// m.white_cells_mut().for_each(|x| *x = 2);
+    ///
+    /// assert_eq!([
+    ///     [1, 2, 1, 2],
+    ///     [2, 1, 2, 1],
+    ///     [1, 2, 1, 2]
+    /// ], m);
+    /// ```
+    #[inline]
+    fn black_cells_mut(&mut self) -> CheckeredMut<'_, Self>
+    where Self: Sized
+    {
+        CheckeredMut::new(self, 0)
+    }
+
+    /// Returns an iterator over the white cells of the matrix seen as a checkerboard (mutable).
+    ///
+    /// See [`white_cells`](MatrixExt::white_cells).
+    #[inline]
+    fn white_cells_mut(&mut self) -> CheckeredMut<'_, Self>
+    where Self: Sized
+    {
+        CheckeredMut::new(self, 1)
+    }
+
+    /// Returns a [`CursorMut`] pointing to the element at the intersection of the `i`-th row and the `j`-th column,
+    /// or `None` if those indexes are out of bounds.
+    #[inline]
+    fn cursor_mut(&mut self, i: usize, j: usize) -> Option<CursorMut<'_, Self>>
+    where Self: Sized
+    {
+        CursorMut::new(self, i, j)
+    }
+
     /// [`.enumerate()`] with mutable access to each element.
     ///
     /// # Example
@@ -1871,7 +4017,7 @@ pub trait MatrixMutExt: MatrixExt {
     ///
     /// ```
     #[inline]
-    fn rows_mut(&mut self) -> RowsMut<Self> where Self: Sized {
+    fn rows_mut(&mut self) -> RowsMut<'_, Self> where Self: Sized {
         RowsMut::from(self) 
     }
 
@@ -1890,7 +4036,7 @@ pub trait MatrixMutExt: MatrixExt {
     /// assert!(cols.next().is_none());
     /// ```
     #[inline]
-    fn cols_mut (&mut self) -> ColumnsMut<Self> where Self: Sized {
+    fn cols_mut (&mut self) -> ColumnsMut<'_, Self> where Self: Sized {
         ColumnsMut::from(self) 
     }
     
@@ -1917,9 +4063,35 @@ pub trait MatrixMutExt: MatrixExt {
     /// ], m);
     /// ```
     #[inline]
-    fn diags_mut (&mut self) -> DiagsMut<Self> where Self: Sized {
+    fn diags_mut (&mut self) -> DiagsMut<'_, Self> where Self: Sized {
         DiagsMut::from(self) 
     }
+
+    /// Returns an iterator over the mutable anti-diagonals of the matrix,
+    /// from the top-left corner to the bottom-right corner.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixMutExt;
+    ///
+    /// let mut m = [[0; 3]; 3];
+    ///
+    /// for (k, antidiag) in m.antidiags_mut().enumerate() {
+    ///     for elem in antidiag {
+    ///         *elem = k;
+    ///     }
+    /// }
+    ///
+    /// assert_eq!([
+    ///     [0, 1, 2],
+    ///     [1, 2, 3],
+    ///     [2, 3, 4]
+    /// ], m);
+    /// ```
+    #[inline]
+    fn antidiags_mut(&mut self) -> AntiDiagsMut<'_, Self> where Self: Sized {
+        AntiDiagsMut::from(self) 
+    }
     
     /// Creates a matrix to mutably access elements of this matrix following an `AccessStrategy`.
     ///
@@ -1959,6 +4131,43 @@ pub trait MatrixMutExt: MatrixExt {
         AccessMut::new(self, strategy)
     }
     
+    /// Gives a view of the matrix where each element is seen as a [`Cell`](::core::cell::Cell),
+    /// allowing elements to be modified through a shared reference.
+    ///
+    /// Only matrices with a contiguous storage ([`MatrixSlicesMut`]) can be seen this way.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::{MatrixExt, MatrixMutExt};
+    ///
+    /// let mut m = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    ///     [7, 8, 9]
+    /// ];
+    ///
+    /// {
+    ///     let cells = m.as_cells();
+    ///
+    ///     // Swap each element with its symmetric while iterating.
+    ///     for (i, j, cell) in cells.enumerate() {
+    ///         if i < j {
+    ///             cell.swap(cells.get(j, i).unwrap());
+    ///         }
+    ///     }
+    ///
+    ///     cells.set((1, 1), 0).unwrap();
+    /// }
+    ///
+    /// assert_eq!([[1, 4, 7], [2, 0, 8], [3, 6, 9]], m);
+    /// ```
+    #[inline]
+    fn as_cells(&mut self) -> CellMatrix<'_, Self::Element>
+    where Self: Sized + MatrixSlicesMut {
+        let shape = self.shape();
+        CellMatrix::new(self.as_flat_slice_mut(), shape)
+    }
+    
     /// Modifies the matrix [`InPlace`] according to a certain strategy. 
     #[inline]
     fn in_place<S: InPlace<Self>>(&mut self, strategy: S)
@@ -1967,3 +4176,198 @@ pub trait MatrixMutExt: MatrixExt {
     }
 
 }
+
+
+/// Computes the extremum of each `h` x `w` window of a matrix, first along rows then along columns,
+/// using a monotonic queue. `keep(a, b)` tells if `a` is preferred over `b`.
+fn window_extremum<M, O>(m: &M, h: usize, w: usize, keep: impl Fn(&M::Element, &M::Element) -> bool) -> O
+where
+    M: MatrixExt,
+    M::Element: Clone,
+    O: MatrixExtFromIter<M::Element>
+{
+    use alloc::collections::VecDeque;
+
+    if h == 0 || w == 0 {
+        panic!("Window dimensions must be greater than zero.")
+    }
+    if h > m.num_rows() || w > m.num_cols() {
+        panic!("Window does not fit inside the matrix.")
+    }
+
+    // Pushes the extremum of each window of `size` consecutive items into `out`.
+    fn slide<'a, T>(
+        len: usize,
+        size: usize,
+        item: impl Fn(usize) -> &'a T,
+        keep: &impl Fn(&T, &T) -> bool,
+        out: &mut Vec<&'a T>
+    ) {
+        let mut queue: VecDeque<usize> = VecDeque::new();
+        for k in 0..len {
+            while queue.back().is_some_and(|&b| keep(item(k), item(b))) {
+                queue.pop_back();
+            }
+            queue.push_back(k);
+            if queue[0] + size <= k {
+                queue.pop_front();
+            }
+            if k + 1 >= size {
+                out.push(item(queue[0]));
+            }
+        }
+    }
+
+    let (rows, cols) = m.shape();
+    let out_cols = cols - w + 1;
+
+    let mut horizontal = Vec::with_capacity(rows * out_cols);
+    for i in 0..rows {
+        slide(cols, w, |j| m.get(i, j).unwrap(), &keep, &mut horizontal);
+    }
+
+    let mut by_cols = Vec::with_capacity((rows - h + 1) * out_cols);
+    for j in 0..out_cols {
+        slide(rows, h, |i| horizontal[i * out_cols + j], &keep, &mut by_cols);
+    }
+
+    // `by_cols` follows the Column Major Order.
+    let out_rows = rows - h + 1;
+    let v: Vec<M::Element> = (0..out_rows * out_cols)
+        .map(|n| by_cols[(n % out_cols) * out_rows + n / out_cols].clone())
+        .collect();
+    O::from_iter(v, out_cols)
+}
+
+
+/// LU decomposition with partial pivoting of a square matrix: `P·A = L·U`.
+struct Lu<T> {
+    n: usize,
+    /// `L` (below the diagonal, with an implicit unit diagonal) and `U` in *Row Major Order*.
+    lu: Vec<T>,
+    /// `perm[i]` is the row of `A` moved to row `i`.
+    perm: Vec<usize>,
+}
+
+impl<T: Float> Lu<T> {
+    /// Returns `None` if the matrix is singular.
+    fn new<M: MatrixExt<Element = T>>(m: &M) -> Option<Self> {
+        let n = m.num_rows();
+        let mut lu: Vec<T> = (0..n * n).map(|k| *m.get(k / n, k % n).unwrap()).collect();
+        let mut perm: Vec<usize> = (0..n).collect();
+
+        for c in 0..n {
+            let p = (c..n).max_by(|&a, &b| {
+                lu[a * n + c].abs().partial_cmp(&lu[b * n + c].abs()).unwrap_or(::core::cmp::Ordering::Equal)
+            })?;
+            if lu[p * n + c] == T::ZERO {
+                return None
+            }
+            if p != c {
+                perm.swap(p, c);
+                for j in 0..n {
+                    lu.swap(p * n + j, c * n + j);
+                }
+            }
+            for i in c + 1..n {
+                let f = lu[i * n + c] / lu[c * n + c];
+                lu[i * n + c] = f;
+                for j in c + 1..n {
+                    lu[i * n + j] = lu[i * n + j] - f * lu[c * n + j];
+                }
+            }
+        }
+        Some(Self { n, lu, perm })
+    }
+
+    /// Replaces `x` by the solution of `A·y = x`.
+    fn solve(&self, x: &mut [T]) {
+        let (n, lu) = (self.n, &self.lu);
+        let b: Vec<T> = self.perm.iter().map(|&p| x[p]).collect();
+        x.copy_from_slice(&b);
+        for i in 0..n {
+            x[i] = (0..i).fold(x[i], |acc, j| acc - lu[i * n + j] * x[j]);
+        }
+        for i in (0..n).rev() {
+            x[i] = (i + 1..n).fold(x[i], |acc, j| acc - lu[i * n + j] * x[j]) / lu[i * n + i];
+        }
+    }
+
+    /// Replaces `x` by the solution of `Aᵀ·y = x`.
+    fn solve_transposed(&self, x: &mut [T]) {
+        let (n, lu) = (self.n, &self.lu);
+        for i in 0..n {
+            x[i] = (0..i).fold(x[i], |acc, j| acc - lu[j * n + i] * x[j]) / lu[i * n + i];
+        }
+        for i in (0..n).rev() {
+            x[i] = (i + 1..n).fold(x[i], |acc, j| acc - lu[j * n + i] * x[j]);
+        }
+        let mut y = alloc::vec![T::ZERO; n];
+        self.perm.iter().zip(x.iter()).for_each(|(&p, &v)| y[p] = v);
+        x.copy_from_slice(&y);
+    }
+}
+
+/// Estimates the condition number of `m` in the 1-norm, or in the infinity norm if `inf` is true.
+///
+/// `‖A⁻¹‖₁` is estimated using Hager's method; `‖A⁻¹‖∞` is `‖A⁻ᵀ‖₁`.
+fn condition_estimate<M>(m: &M, inf: bool) -> Option<M::Element>
+where
+    M: MatrixExt,
+    M::Element: Float
+{
+    type Solver<T> = fn(&Lu<T>, &mut [T]);
+
+    let n = m.num_rows();
+    if n == 0 || !m.is_square() {
+        return None
+    }
+    let max = |a: M::Element, b: M::Element| if b > a { b } else { a };
+    let abs_sum = |(i, j): (usize, usize)| (0..n).fold(M::Element::ZERO, |acc, k| {
+        acc + if inf { m.get(i, k) } else { m.get(k, j) }.unwrap().abs()
+    });
+    let norm = (0..n).map(|k| abs_sum((k, k))).fold(M::Element::ZERO, max);
+
+    let lu = Lu::new(m)?;
+    let (solve, solve_t): (Solver<M::Element>, Solver<M::Element>) = match inf {
+        false => (Lu::solve, Lu::solve_transposed),
+        true => (Lu::solve_transposed, Lu::solve),
+    };
+
+    let mut x = alloc::vec![M::Element::ONE / M::Element::from_usize(n); n];
+    let mut estimate = M::Element::ZERO;
+    for _ in 0..5 {
+        let mut y = x.clone();
+        solve(&lu, &mut y);
+        estimate = y.iter().fold(M::Element::ZERO, |acc, v| acc + v.abs());
+
+        let mut z: Vec<M::Element> = y.iter()
+            .map(|&v| if v < M::Element::ZERO { -M::Element::ONE } else { M::Element::ONE })
+            .collect();
+        solve_t(&lu, &mut z);
+
+        let (j, zj) = z.iter().enumerate().fold((0, M::Element::ZERO), |(j, zj), (k, v)| {
+            if v.abs() > zj { (k, v.abs()) } else { (j, zj) }
+        });
+        let zx = z.iter().zip(x.iter()).fold(M::Element::ZERO, |acc, (&a, &b)| acc + a * b);
+        if zj <= zx {
+            break
+        }
+        x.iter_mut().for_each(|v| *v = M::Element::ZERO);
+        x[j] = M::Element::ONE;
+    }
+
+    // Higham's safeguard against the cases where the iterations above stop too early.
+    let den = M::Element::from_usize(n.saturating_sub(1).max(1));
+    let mut x: Vec<M::Element> = (0..n)
+        .map(|i| {
+            let v = M::Element::ONE + M::Element::from_usize(i) / den;
+            if i % 2 == 0 { v } else { -v }
+        })
+        .collect();
+    solve(&lu, &mut x);
+    let alternate = x.iter().fold(M::Element::ZERO, |acc, v| acc + v.abs());
+    let alternate = (alternate + alternate) / M::Element::from_usize(3 * n);
+
+    Some(norm * max(estimate, alternate))
+}
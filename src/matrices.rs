@@ -0,0 +1,1817 @@
+//! Matrix types with a compact storage, for matrices following a known structure.
+
+use ::core::cell::{ OnceCell, RefCell };
+use ::core::ops::{ Add, Mul, Range, Sub };
+
+use alloc::boxed::Box;
+use alloc::collections::btree_map::{ self, BTreeMap };
+use alloc::vec::Vec;
+
+use std::collections::hash_map::{ self, HashMap };
+
+use crate::{ MatrixExt, MatrixMutExt };
+use crate::req::{ MatrixExtFromIter, MatrixSlices, MatrixSlicesMut };
+
+#[cfg(feature = "serde")]
+use serde::{ Serialize, Deserialize };
+
+
+/// A square matrix equal to its transpose, only storing its upper triangle.
+///
+/// Elements `(i, j)` and `(j, i)` share the same storage, so writing one of them changes the other.
+/// This uses `n * (n + 1) / 2` elements instead of `n * n`.
+///
+/// # Example
+/// ```rust
+/// use matrixable::prelude::*;
+/// use matrixable::matrices::SymmetricMatrix;
+///
+/// let mut m = SymmetricMatrix::from_fn(3, |i, j| i * 3 + j);
+///
+/// let expected = [
+///     [0, 1, 2],
+///     [1, 4, 5],
+///     [2, 5, 8]
+/// ];
+/// assert!(expected.rows().eq(m.rows()));
+///
+/// *m.get_mut(2, 0).unwrap() = 10;
+/// assert_eq!(Some(&10), m.get(0, 2));
+///
+/// assert!(m.is_symmetric());
+/// assert_eq!(6, m.as_packed().len());
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Hash, PartialEq, Eq, Clone, Debug)]
+pub struct SymmetricMatrix<T> {
+    data: Vec<T>,
+    dim: usize,
+}
+
+impl<T> SymmetricMatrix<T> {
+    /// Creates a symmetric matrix of `dim` rows and columns with all its elements equal to `val`.
+    pub fn new(dim: usize, val: T) -> Self
+    where T: Clone
+    {
+        Self { data: alloc::vec![val; dim * (dim + 1) / 2], dim }
+    }
+
+    /// Creates a symmetric matrix of `dim` rows and columns, where `f(i, j)` is called for
+    /// each element of the upper triangle (`i <= j`) in *Row Major Order*.
+    pub fn from_fn(dim: usize, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let mut data = Vec::with_capacity(dim * (dim + 1) / 2);
+        for i in 0..dim {
+            for j in i..dim {
+                data.push(f(i, j));
+            }
+        }
+        Self { data, dim }
+    }
+
+    /// Creates a symmetric matrix from the elements of its upper triangle in *Row Major Order*.
+    ///
+    /// # Error
+    /// An error is returned if `packed` does not contain exactly `dim * (dim + 1) / 2` elements.
+    pub fn from_packed(dim: usize, packed: Vec<T>) -> Result<Self, &'static str> {
+        if packed.len() != dim * (dim + 1) / 2 {
+            return Err("Number of elements does not match the upper triangle of the matrix.")
+        }
+        Ok(Self { data: packed, dim })
+    }
+
+    /// Returns the number of rows (or columns) of the matrix.
+    #[inline]
+    pub fn dim(&self) -> usize { self.dim }
+
+    /// Returns the elements of the upper triangle in *Row Major Order*.
+    #[inline]
+    pub fn as_packed(&self) -> &[T] { &self.data }
+
+    /// Consumes the matrix and returns the elements of its upper triangle in *Row Major Order*.
+    #[inline]
+    pub fn into_packed(self) -> Vec<T> { self.data }
+
+    /// Position of element `(i, j)` inside the packed storage.
+    #[inline]
+    fn index(&self, i: usize, j: usize) -> Option<usize> {
+        if i >= self.dim || j >= self.dim {
+            return None
+        }
+        let (i, j) = if i <= j { (i, j) } else { (j, i) };
+        Some(i * (2 * self.dim - i + 1) / 2 + (j - i))
+    }
+}
+
+impl<T> MatrixExt for SymmetricMatrix<T> {
+    type Element = T;
+
+    #[inline]
+    fn num_rows(&self) -> usize { self.dim }
+
+    #[inline]
+    fn num_cols(&self) -> usize { self.dim }
+
+    #[inline]
+    fn get(&self, row: usize, column: usize) -> Option<&Self::Element> {
+        self.data.get(self.index(row, column)?)
+    }
+}
+
+impl<T> MatrixMutExt for SymmetricMatrix<T> {
+    #[inline]
+    fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut Self::Element> {
+        let n = self.index(row, column)?;
+        self.data.get_mut(n)
+    }
+}
+
+
+/// Which half of a square matrix is stored by a [`TriangularMatrix`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Hash, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Triangle {
+    /// Elements on and above the main diagonal (`i <= j`).
+    Upper,
+    /// Elements on and below the main diagonal (`i >= j`).
+    Lower,
+}
+
+/// A square matrix whose elements outside one of its triangles are all zero.
+///
+/// Only the elements of the chosen [`Triangle`] (diagonal included) are stored, using `n * (n + 1) / 2` elements;
+/// the other half always yields the same `zero` element.
+///
+/// # Example
+/// ```rust
+/// use matrixable::prelude::*;
+/// use matrixable::matrices::{ TriangularMatrix, Triangle };
+///
+/// let mut m = TriangularMatrix::from_fn(3, Triangle::Lower, 0, |i, j| i + j + 1);
+///
+/// let expected = [
+///     [1, 0, 0],
+///     [2, 3, 0],
+///     [3, 4, 5]
+/// ];
+/// assert!(expected.rows().eq(m.rows()));
+///
+/// *m.get_stored_mut(2, 0).unwrap() = 10;
+/// assert_eq!(Some(&10), m.get(2, 0));
+///
+/// // Elements of the other half cannot be modified.
+/// assert_eq!(None, m.get_stored_mut(0, 2));
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Hash, PartialEq, Eq, Clone, Debug)]
+pub struct TriangularMatrix<T> {
+    data: Vec<T>,
+    dim: usize,
+    triangle: Triangle,
+    zero: T,
+}
+
+impl<T> TriangularMatrix<T> {
+    /// Creates a triangular matrix of `dim` rows and columns, where `f(i, j)` is called for
+    /// each element of the stored triangle in *Row Major Order*.
+    pub fn from_fn(dim: usize, triangle: Triangle, zero: T, mut f: impl FnMut(usize, usize) -> T) -> Self {
+        let mut data = Vec::with_capacity(dim * (dim + 1) / 2);
+        for i in 0..dim {
+            let range = match triangle {
+                Triangle::Upper => i..dim,
+                Triangle::Lower => 0..(i + 1),
+            };
+            for j in range {
+                data.push(f(i, j));
+            }
+        }
+        Self { data, dim, triangle, zero }
+    }
+
+    /// Creates a triangular matrix from the elements of its stored triangle in *Row Major Order*.
+    ///
+    /// # Error
+    /// An error is returned if `packed` does not contain exactly `dim * (dim + 1) / 2` elements.
+    pub fn from_packed(dim: usize, triangle: Triangle, zero: T, packed: Vec<T>) -> Result<Self, &'static str> {
+        if packed.len() != dim * (dim + 1) / 2 {
+            return Err("Number of elements does not match the triangle of the matrix.")
+        }
+        Ok(Self { data: packed, dim, triangle, zero })
+    }
+
+    /// Returns the number of rows (or columns) of the matrix.
+    #[inline]
+    pub fn dim(&self) -> usize { self.dim }
+
+    /// Returns the half of the matrix which is stored.
+    #[inline]
+    pub fn triangle(&self) -> Triangle { self.triangle }
+
+    /// Returns the elements of the stored triangle in *Row Major Order*.
+    #[inline]
+    pub fn as_packed(&self) -> &[T] { &self.data }
+
+    /// Consumes the matrix and returns the elements of its stored triangle in *Row Major Order*.
+    #[inline]
+    pub fn into_packed(self) -> Vec<T> { self.data }
+
+    /// Returns a mutable reference to an element of the stored triangle.
+    ///
+    /// `None` is returned if indexes are out of bounds or if they point to the zero half of the matrix.
+    #[inline]
+    pub fn get_stored_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+        let n = self.index(row, column)?;
+        self.data.get_mut(n)
+    }
+
+    /// Position of element `(i, j)` inside the packed storage, if it is stored.
+    #[inline]
+    fn index(&self, i: usize, j: usize) -> Option<usize> {
+        if i >= self.dim || j >= self.dim {
+            return None
+        }
+        match self.triangle {
+            Triangle::Upper if i <= j => Some(i * (2 * self.dim - i + 1) / 2 + (j - i)),
+            Triangle::Lower if i >= j => Some(i * (i + 1) / 2 + j),
+            _ => None,
+        }
+    }
+}
+
+impl<T> MatrixExt for TriangularMatrix<T> {
+    type Element = T;
+
+    #[inline]
+    fn num_rows(&self) -> usize { self.dim }
+
+    #[inline]
+    fn num_cols(&self) -> usize { self.dim }
+
+    #[inline]
+    fn get(&self, row: usize, column: usize) -> Option<&Self::Element> {
+        if row >= self.dim || column >= self.dim {
+            return None
+        }
+        match self.index(row, column) {
+            Some(n) => self.data.get(n),
+            None => Some(&self.zero),
+        }
+    }
+}
+
+
+/// A square matrix whose elements outside its main diagonal are all zero.
+///
+/// Only the diagonal is stored; the other elements always yield the same `zero` element.
+///
+/// # Example
+/// ```rust
+/// use matrixable::prelude::*;
+/// use matrixable::matrices::DiagonalMatrix;
+///
+/// let d = DiagonalMatrix::new(vec![1, 2, 3], 0);
+///
+/// let expected = [
+///     [1, 0, 0],
+///     [0, 2, 0],
+///     [0, 0, 3]
+/// ];
+/// assert!(expected.rows().eq(d.rows()));
+/// assert!(d.is_diagonal().0);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Hash, PartialEq, Eq, Clone, Debug)]
+pub struct DiagonalMatrix<T> {
+    diag: Vec<T>,
+    zero: T,
+}
+
+impl<T> DiagonalMatrix<T> {
+    /// Creates a diagonal matrix from the elements of its main diagonal.
+    #[inline]
+    pub fn new(diag: Vec<T>, zero: T) -> Self {
+        Self { diag, zero }
+    }
+
+    /// Returns the number of rows (or columns) of the matrix.
+    #[inline]
+    pub fn dim(&self) -> usize { self.diag.len() }
+
+    /// Returns the elements of the main diagonal.
+    #[inline]
+    pub fn as_diag(&self) -> &[T] { &self.diag }
+
+    /// Returns the elements of the main diagonal as a mutable slice.
+    #[inline]
+    pub fn as_diag_mut(&mut self) -> &mut [T] { &mut self.diag }
+
+    /// Consumes the matrix and returns the elements of its main diagonal.
+    #[inline]
+    pub fn into_diag(self) -> Vec<T> { self.diag }
+
+    /// Performs the matrix product `self * m`, which scales each row of `m` by the matching diagonal element.
+    ///
+    /// Only `rows * cols` multiplications are performed instead of `rows * rows * cols`.
+    ///
+    /// # Panics
+    /// Panics if the number of rows of `m` is different from the dimension of the diagonal matrix.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::matrices::DiagonalMatrix;
+    ///
+    /// let d = DiagonalMatrix::new(vec![2, 3], 0);
+    /// let m = [[1, 2, 3], [4, 5, 6]];
+    ///
+    /// let p: [[i32; 3]; 2] = d.mul(&m);
+    /// assert_eq!([[2, 4, 6], [12, 15, 18]], p);
+    /// ```
+    pub fn mul<M, U, O>(&self, m: &M) -> O
+    where
+        M: MatrixExt,
+        for<'x> &'x T: Mul<&'x M::Element, Output = U>,
+        O: MatrixExtFromIter<U>,
+    {
+        if m.num_rows() != self.dim() {
+            panic!("Cannot multiply a {:?} matrix by a {:?} matrix.", (self.dim(), self.dim()), m.shape())
+        }
+        let v: Vec<U> = m.enumerate().map(|(i, _, x)| &self.diag[i] * x).collect();
+        O::from_iter(v, m.num_cols())
+    }
+
+    /// Performs the matrix product `m * self`, which scales each column of `m` by the matching diagonal element.
+    ///
+    /// Only `rows * cols` multiplications are performed instead of `rows * cols * cols`.
+    ///
+    /// # Panics
+    /// Panics if the number of columns of `m` is different from the dimension of the diagonal matrix.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::matrices::DiagonalMatrix;
+    ///
+    /// let d = DiagonalMatrix::new(vec![2, 3, 4], 0);
+    /// let m = [[1, 2, 3], [4, 5, 6]];
+    ///
+    /// let p: [[i32; 3]; 2] = d.rmul(&m);
+    /// assert_eq!([[2, 6, 12], [8, 15, 24]], p);
+    /// ```
+    pub fn rmul<M, U, O>(&self, m: &M) -> O
+    where
+        M: MatrixExt,
+        for<'x> &'x M::Element: Mul<&'x T, Output = U>,
+        O: MatrixExtFromIter<U>,
+    {
+        if m.num_cols() != self.dim() {
+            panic!("Cannot multiply a {:?} matrix by a {:?} matrix.", m.shape(), (self.dim(), self.dim()))
+        }
+        let v: Vec<U> = m.enumerate().map(|(_, j, x)| x * &self.diag[j]).collect();
+        O::from_iter(v, m.num_cols())
+    }
+}
+
+impl<T> MatrixExt for DiagonalMatrix<T> {
+    type Element = T;
+
+    #[inline]
+    fn num_rows(&self) -> usize { self.diag.len() }
+
+    #[inline]
+    fn num_cols(&self) -> usize { self.diag.len() }
+
+    #[inline]
+    fn get(&self, row: usize, column: usize) -> Option<&Self::Element> {
+        if row >= self.dim() || column >= self.dim() {
+            None
+        }
+        else if row == column {
+            self.diag.get(row)
+        }
+        else {
+            Some(&self.zero)
+        }
+    }
+}
+
+
+/// A matrix storing runs of consecutive equal elements (in *Row Major Order*) only once.
+///
+/// Accessing an element performs a binary search among the runs, so it costs `O(log r)` where `r` is the number of runs.
+/// This is well suited to matrices made of large constant regions, like tile maps or label grids.
+///
+/// # Example
+/// ```rust
+/// use matrixable::prelude::*;
+/// use matrixable::matrices::RleMatrix;
+///
+/// let grid = [
+///     [0, 0, 0, 0],
+///     [0, 1, 1, 0],
+///     [0, 0, 0, 0]
+/// ];
+///
+/// let rle: RleMatrix<i32> = RleMatrix::from_iter(grid.iter().copied(), 4);
+///
+/// assert_eq!(3, rle.num_runs());
+/// assert!(grid.rows().eq(rle.rows()));
+/// assert_eq!(vec![(&0, 5), (&1, 2), (&0, 5)], rle.runs().collect::<Vec<_>>());
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Hash, PartialEq, Eq, Clone, Debug)]
+pub struct RleMatrix<T> {
+    values: Vec<T>,
+    // Exclusive end position of each run.
+    ends: Vec<usize>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> RleMatrix<T> {
+    /// Returns the number of runs of equal elements.
+    #[inline]
+    pub fn num_runs(&self) -> usize { self.values.len() }
+
+    /// Returns an iterator over the runs of the matrix, as the repeated element and the length of the run.
+    pub fn runs(&self) -> impl Iterator<Item = (&T, usize)> + '_ {
+        self.values
+            .iter()
+            .zip(self.ends.iter())
+            .scan(0, |start, (val, &end)| {
+                let len = end - *start;
+                *start = end;
+                Some((val, len))
+            })
+    }
+}
+
+impl<T: PartialEq> MatrixExtFromIter<T> for RleMatrix<T> {
+    /// # Panics
+    /// Panics if the number of elements is not a multiple of `columns`.
+    fn from_iter<I>(into_iter: I, columns: usize) -> Self
+        where I: IntoIterator<Item = T>,
+             <I as IntoIterator>::IntoIter: ExactSizeIterator
+    {
+        let iter = into_iter.into_iter();
+        let len = iter.len();
+        if !len.is_multiple_of(columns) {
+            panic!("Number of elements is not a multiple of the number of columns.")
+        }
+
+        let mut values: Vec<T> = Vec::new();
+        let mut ends: Vec<usize> = Vec::new();
+        for (n, x) in iter.enumerate() {
+            match values.last() {
+                Some(last) if *last == x => *ends.last_mut().unwrap() = n + 1,
+                _ => {
+                    values.push(x);
+                    ends.push(n + 1);
+                }
+            }
+        }
+
+        let rows = len.checked_div(columns).unwrap_or(0);
+        Self { values, ends, rows, cols: columns }
+    }
+}
+
+impl<T> MatrixExt for RleMatrix<T> {
+    type Element = T;
+
+    #[inline]
+    fn num_rows(&self) -> usize { self.rows }
+
+    #[inline]
+    fn num_cols(&self) -> usize { self.cols }
+
+    #[inline]
+    fn get(&self, row: usize, column: usize) -> Option<&Self::Element> {
+        if row >= self.rows || column >= self.cols {
+            return None
+        }
+        let n = row * self.cols + column;
+        self.values.get(self.ends.partition_point(|&end| end <= n))
+    }
+}
+
+
+/// A matrix whose descending diagonals are constant, defined by its first column and its first row.
+///
+/// Element `(i, j)` is `first_row[j - i]` if `j >= i` and `first_col[i - j]` otherwise,
+/// so only `rows + cols` elements are stored.
+///
+/// # Example
+/// ```rust
+/// use matrixable::prelude::*;
+/// use matrixable::matrices::Toeplitz;
+///
+/// let t = Toeplitz::new(vec![1, 4, 5], vec![1, 2, 3, 0]).unwrap();
+///
+/// let expected = [
+///     [1, 2, 3, 0],
+///     [4, 1, 2, 3],
+///     [5, 4, 1, 2]
+/// ];
+/// assert!(expected.rows().eq(t.rows()));
+///
+/// let dense: [[i32; 4]; 3] = t.to_dense();
+/// assert_eq!(expected, dense);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Hash, PartialEq, Eq, Clone, Debug)]
+pub struct Toeplitz<T> {
+    first_col: Vec<T>,
+    first_row: Vec<T>,
+}
+
+impl<T> Toeplitz<T> {
+    /// Creates a Toeplitz matrix from its first column and its first row.
+    ///
+    /// # Error
+    /// An error is returned if only one of them is empty or if they do not start with the same element.
+    pub fn new(first_col: Vec<T>, first_row: Vec<T>) -> Result<Self, &'static str>
+    where T: PartialEq
+    {
+        match (first_col.first(), first_row.first()) {
+            (None, None) => Ok(Self { first_col, first_row }),
+            (Some(a), Some(b)) if a == b => Ok(Self { first_col, first_row }),
+            _ => Err("First column and first row must start with the same element."),
+        }
+    }
+
+    /// Returns the first column of the matrix.
+    #[inline]
+    pub fn first_col(&self) -> &[T] { &self.first_col }
+
+    /// Returns the first row of the matrix.
+    #[inline]
+    pub fn first_row(&self) -> &[T] { &self.first_row }
+
+    /// Creates a matrix containing a copy of each element of the Toeplitz matrix.
+    pub fn to_dense<O>(&self) -> O
+    where
+        T: Clone,
+        O: MatrixExtFromIter<T>
+    {
+        self.to_matrix()
+    }
+}
+
+impl<T> MatrixExt for Toeplitz<T> {
+    type Element = T;
+
+    #[inline]
+    fn num_rows(&self) -> usize { self.first_col.len() }
+
+    #[inline]
+    fn num_cols(&self) -> usize { self.first_row.len() }
+
+    #[inline]
+    fn get(&self, row: usize, column: usize) -> Option<&Self::Element> {
+        if row >= self.num_rows() || column >= self.num_cols() {
+            None
+        }
+        else if column >= row {
+            self.first_row.get(column - row)
+        }
+        else {
+            self.first_col.get(row - column)
+        }
+    }
+}
+
+
+/// A square matrix whose rows are circular shifts of its first row.
+///
+/// Element `(i, j)` is `first_row[(j - i) mod n]`, so only `n` elements are stored.
+///
+/// # Example
+/// ```rust
+/// use matrixable::prelude::*;
+/// use matrixable::matrices::Circulant;
+///
+/// let c = Circulant::new(vec![1, 2, 3]);
+///
+/// let expected = [
+///     [1, 2, 3],
+///     [3, 1, 2],
+///     [2, 3, 1]
+/// ];
+/// assert!(expected.rows().eq(c.rows()));
+///
+/// let dense: [[i32; 3]; 3] = c.to_dense();
+/// assert_eq!(expected, dense);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Hash, PartialEq, Eq, Clone, Debug)]
+pub struct Circulant<T> {
+    first_row: Vec<T>,
+}
+
+impl<T> Circulant<T> {
+    /// Creates a circulant matrix from its first row.
+    #[inline]
+    pub fn new(first_row: Vec<T>) -> Self {
+        Self { first_row }
+    }
+
+    /// Returns the first row of the matrix.
+    #[inline]
+    pub fn first_row(&self) -> &[T] { &self.first_row }
+
+    /// Creates a matrix containing a copy of each element of the circulant matrix.
+    pub fn to_dense<O>(&self) -> O
+    where
+        T: Clone,
+        O: MatrixExtFromIter<T>
+    {
+        self.to_matrix()
+    }
+}
+
+impl<T> MatrixExt for Circulant<T> {
+    type Element = T;
+
+    #[inline]
+    fn num_rows(&self) -> usize { self.first_row.len() }
+
+    #[inline]
+    fn num_cols(&self) -> usize { self.first_row.len() }
+
+    #[inline]
+    fn get(&self, row: usize, column: usize) -> Option<&Self::Element> {
+        let n = self.first_row.len();
+        if row >= n || column >= n {
+            return None
+        }
+        self.first_row.get((column + n - row) % n)
+    }
+}
+
+
+/// A matrix whose elements are all the same, stored only once.
+///
+/// # Example
+/// ```rust
+/// use matrixable::prelude::*;
+/// use matrixable::matrices::ConstantMatrix;
+///
+/// let m = ConstantMatrix::new(7, 2, 3);
+///
+/// assert_eq!((2, 3), m.shape());
+/// assert!(m.iter().all(|&x| x == 7));
+/// assert_eq!(None, m.get(2, 0));
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Hash, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct ConstantMatrix<T> {
+    value: T,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> ConstantMatrix<T> {
+    /// Creates a matrix of `rows` rows and `cols` columns with all its elements equal to `value`.
+    #[inline]
+    pub fn new(value: T, rows: usize, cols: usize) -> Self {
+        Self { value, rows, cols }
+    }
+
+    /// Returns the element repeated by the matrix.
+    #[inline]
+    pub fn value(&self) -> &T { &self.value }
+}
+
+impl<T> MatrixExt for ConstantMatrix<T> {
+    type Element = T;
+
+    #[inline]
+    fn num_rows(&self) -> usize { self.rows }
+
+    #[inline]
+    fn num_cols(&self) -> usize { self.cols }
+
+    #[inline]
+    fn get(&self, row: usize, column: usize) -> Option<&Self::Element> {
+        if row >= self.rows || column >= self.cols {
+            return None
+        }
+        Some(&self.value)
+    }
+}
+
+
+/// The identity matrix, storing only its `one` and `zero` elements.
+///
+/// # Example
+/// ```rust
+/// use matrixable::prelude::*;
+/// use matrixable::matrices::IdentityMatrix;
+/// use matrixable::ops::matmul;
+///
+/// let id = IdentityMatrix::new(3, 1, 0);
+///
+/// assert!([[1, 0, 0], [0, 1, 0], [0, 0, 1]].rows().eq(id.rows()));
+///
+/// let m = [[1, 2], [3, 4], [5, 6]];
+/// let p: [[i32; 2]; 3] = matmul(&id, &m);
+/// assert_eq!(m, p);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Hash, PartialEq, Eq, Clone, Copy, Debug)]
+pub struct IdentityMatrix<T> {
+    dim: usize,
+    one: T,
+    zero: T,
+}
+
+impl<T> IdentityMatrix<T> {
+    /// Creates an identity matrix of `dim` rows and columns.
+    ///
+    /// `one` is the element of the main diagonal and `zero` the element everywhere else.
+    #[inline]
+    pub fn new(dim: usize, one: T, zero: T) -> Self {
+        Self { dim, one, zero }
+    }
+
+    /// Returns the number of rows (or columns) of the matrix.
+    #[inline]
+    pub fn dim(&self) -> usize { self.dim }
+}
+
+impl<T> MatrixExt for IdentityMatrix<T> {
+    type Element = T;
+
+    #[inline]
+    fn num_rows(&self) -> usize { self.dim }
+
+    #[inline]
+    fn num_cols(&self) -> usize { self.dim }
+
+    #[inline]
+    fn get(&self, row: usize, column: usize) -> Option<&Self::Element> {
+        if row >= self.dim || column >= self.dim {
+            None
+        }
+        else if row == column {
+            Some(&self.one)
+        }
+        else {
+            Some(&self.zero)
+        }
+    }
+}
+
+
+/// A matrix whose elements are produced by a function of their position.
+///
+/// Elements are computed the first time they are accessed and kept afterwards,
+/// so that [`get`](MatrixExt::get) can return a reference to them.
+/// The storage for all `rows * cols` elements is allocated upfront; see [`LazyMatrix`] for sparse reads.
+///
+/// # Example
+/// ```rust
+/// use matrixable::prelude::*;
+/// use matrixable::matrices::FnMatrix;
+///
+/// let ramp = FnMatrix::new(2, 3, |i, j| 10 * i + j);
+///
+/// let expected = [
+///     [ 0,  1,  2],
+///     [10, 11, 12]
+/// ];
+/// assert!(expected.rows().eq(ramp.rows()));
+/// assert_eq!(Some(&12), ramp.get(1, 2));
+/// assert_eq!(None, ramp.get(2, 0));
+/// ```
+pub struct FnMatrix<T, F: Fn(usize, usize) -> T> {
+    cells: Vec<OnceCell<T>>,
+    rows: usize,
+    cols: usize,
+    f: F,
+}
+
+impl<T, F: Fn(usize, usize) -> T> FnMatrix<T, F> {
+    /// Creates a matrix of `rows` rows and `cols` columns whose element `(i, j)` is `f(i, j)`.
+    pub fn new(rows: usize, cols: usize, f: F) -> Self {
+        let mut cells = Vec::with_capacity(rows * cols);
+        cells.resize_with(rows * cols, OnceCell::new);
+        Self { cells, rows, cols, f }
+    }
+}
+
+impl<T: ::core::fmt::Debug, F: Fn(usize, usize) -> T> ::core::fmt::Debug for FnMatrix<T, F> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("FnMatrix")
+            .field("cells", &self.cells)
+            .field("rows", &self.rows)
+            .field("cols", &self.cols)
+            .finish()
+    }
+}
+
+impl<T, F: Fn(usize, usize) -> T> MatrixExt for FnMatrix<T, F> {
+    type Element = T;
+
+    #[inline]
+    fn num_rows(&self) -> usize { self.rows }
+
+    #[inline]
+    fn num_cols(&self) -> usize { self.cols }
+
+    #[inline]
+    fn get(&self, row: usize, column: usize) -> Option<&Self::Element> {
+        if row >= self.rows || column >= self.cols {
+            return None
+        }
+        let cell = &self.cells[row * self.cols + column];
+        Some(cell.get_or_init(|| (self.f)(row, column)))
+    }
+}
+
+
+/// A matrix whose elements are produced by a function of their position and cached on first access.
+///
+/// Unlike [`FnMatrix`], only the elements which have been accessed are stored,
+/// which suits matrices that are expensive to compute but sparsely read.
+///
+/// # Example
+/// ```rust
+/// use matrixable::prelude::*;
+/// use matrixable::matrices::LazyMatrix;
+///
+/// let mut m = LazyMatrix::new(1000, 1000, |i, j| i * j);
+///
+/// assert_eq!(Some(&6), m.get(2, 3));
+/// assert_eq!(Some(&6), m.get(2, 3));
+/// assert_eq!(Some(&998001), m.get(999, 999));
+/// assert_eq!(None, m.get(1000, 0));
+/// assert_eq!(2, m.num_cached());
+///
+/// m.clear_cache();
+/// assert_eq!(0, m.num_cached());
+/// ```
+pub struct LazyMatrix<T, F: Fn(usize, usize) -> T> {
+    // Elements are boxed so that their address does not change when the map is modified.
+    // They are only removed through `&mut self`.
+    cache: RefCell<BTreeMap<(usize, usize), Box<T>>>,
+    rows: usize,
+    cols: usize,
+    f: F,
+}
+
+impl<T, F: Fn(usize, usize) -> T> LazyMatrix<T, F> {
+    /// Creates a matrix of `rows` rows and `cols` columns whose element `(i, j)` is `f(i, j)`.
+    #[inline]
+    pub fn new(rows: usize, cols: usize, f: F) -> Self {
+        Self { cache: RefCell::new(BTreeMap::new()), rows, cols, f }
+    }
+
+    /// Returns the number of elements which have already been computed.
+    #[inline]
+    pub fn num_cached(&self) -> usize { self.cache.borrow().len() }
+
+    /// Removes all the computed elements. They will be computed again on their next access.
+    #[inline]
+    pub fn clear_cache(&mut self) { self.cache.get_mut().clear() }
+}
+
+impl<T: ::core::fmt::Debug, F: Fn(usize, usize) -> T> ::core::fmt::Debug for LazyMatrix<T, F> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("LazyMatrix")
+            .field("cache", &self.cache)
+            .field("rows", &self.rows)
+            .field("cols", &self.cols)
+            .finish()
+    }
+}
+
+impl<T, F: Fn(usize, usize) -> T> MatrixExt for LazyMatrix<T, F> {
+    type Element = T;
+
+    #[inline]
+    fn num_rows(&self) -> usize { self.rows }
+
+    #[inline]
+    fn num_cols(&self) -> usize { self.cols }
+
+    fn get(&self, row: usize, column: usize) -> Option<&Self::Element> {
+        if row >= self.rows || column >= self.cols {
+            return None
+        }
+
+        let cached = self.cache.borrow().get(&(row, column)).map(|val| &**val as *const T);
+        let ptr = match cached {
+            Some(ptr) => ptr,
+            None => {
+                let val = Box::new((self.f)(row, column));
+                let ptr: *const T = &*val;
+                self.cache.borrow_mut().insert((row, column), val);
+                ptr
+            }
+        };
+
+        // SAFETY: The element is boxed, so its address is stable, and it is never removed
+        // or replaced while `self` is borrowed immutably.
+        Some(unsafe { &*ptr })
+    }
+}
+
+
+/// Two-dimensional prefix sums of a matrix, answering sums over rectangular regions in constant time.
+///
+/// As a `MatrixExt`, element `(i, j)` is the sum of the elements of the original matrix in rows `0..=i` and columns `0..=j`.
+///
+/// This `struct` is created by the [`integral_image`](crate::MatrixExt::integral_image) method on `MatrixExt`. See its documentation for more.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Hash, PartialEq, Eq, Clone, Debug)]
+pub struct IntegralImage<T> {
+    // Prefix sums with an additional leading row and column of zeros.
+    sums: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> IntegralImage<T>
+where T: Copy + Default + Add<Output = T>
+{
+    /// Computes the prefix sums of a matrix.
+    pub fn new<M: MatrixExt<Element = T>>(m: &M) -> Self {
+        let (rows, cols) = m.shape();
+        let mut sums = alloc::vec![T::default(); (rows + 1) * (cols + 1)];
+        for i in 0..rows {
+            let mut row_sum = T::default();
+            for j in 0..cols {
+                row_sum = row_sum + *m.get(i, j).unwrap();
+                sums[(i + 1) * (cols + 1) + j + 1] = sums[i * (cols + 1) + j + 1] + row_sum;
+            }
+        }
+        Self { sums, rows, cols }
+    }
+}
+
+impl<T> IntegralImage<T>
+where T: Copy + Add<Output = T> + Sub<Output = T>
+{
+    /// Returns the sum of the elements in the rectangular region going from the subscripts `region.start`
+    /// (included) to the subscripts `region.end` (excluded).
+    ///
+    /// `None` is returned if the region is out of bounds or if its end comes before its start.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::MatrixExt;
+    ///
+    /// let m = [
+    ///     [1, 2, 3],
+    ///     [4, 5, 6],
+    ///     [7, 8, 9]
+    /// ];
+    /// let integral = m.integral_image();
+    ///
+    /// assert_eq!(Some(5 + 6 + 8 + 9), integral.region_sum((1, 1)..(3, 3)));
+    /// assert_eq!(Some(45), integral.region_sum((0, 0)..(3, 3)));
+    /// assert_eq!(Some(0), integral.region_sum((2, 0)..(2, 3)));
+    /// assert_eq!(None, integral.region_sum((0, 0)..(4, 1)));
+    /// ```
+    pub fn region_sum(&self, region: Range<(usize, usize)>) -> Option<T> {
+        let ((r0, c0), (r1, c1)) = (region.start, region.end);
+        if r0 > r1 || c0 > c1 || r1 > self.rows || c1 > self.cols {
+            return None
+        }
+        let at = |i: usize, j: usize| self.sums[i * (self.cols + 1) + j];
+        Some(at(r1, c1) + at(r0, c0) - at(r0, c1) - at(r1, c0))
+    }
+}
+
+impl<T> MatrixExt for IntegralImage<T> {
+    type Element = T;
+
+    #[inline]
+    fn num_rows(&self) -> usize { self.rows }
+
+    #[inline]
+    fn num_cols(&self) -> usize { self.cols }
+
+    #[inline]
+    fn get(&self, row: usize, column: usize) -> Option<&Self::Element> {
+        if row >= self.rows || column >= self.cols {
+            return None
+        }
+        self.sums.get((row + 1) * (self.cols + 1) + column + 1)
+    }
+}
+
+/// An owned copy of a matrix stored in *Column Major Order*, still answering subscripts in the original orientation.
+///
+/// Each column is contiguous in memory, which benefits algorithms doing many passes over columns.
+///
+/// This `struct` is created by the [`col_cache`](crate::MatrixExt::col_cache) method on `MatrixExt`.
+///
+/// # Example
+/// ```rust
+/// use matrixable::prelude::*;
+///
+/// let m = [
+///     [1, 2, 3],
+///     [4, 5, 6]
+/// ];
+/// let mut cache = m.col_cache();
+///
+/// assert_eq!(Some(&6), cache.get(1, 2));
+/// assert_eq!(Some(&[2, 5][..]), cache.col_slice(1));
+/// assert_eq!(None, cache.col_slice(3));
+/// assert!(m.rows().eq(cache.rows()));
+///
+/// *cache.get_mut(0, 2).unwrap() = 30;
+/// assert_eq!(Some(&[30, 6][..]), cache.col_slice(2));
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Hash, PartialEq, Eq, Clone, Debug)]
+pub struct ColCache<T> {
+    data: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T: Clone> ColCache<T> {
+    /// Clones the elements of a matrix column by column.
+    pub fn new<M: MatrixExt<Element = T>>(m: &M) -> Self {
+        let (rows, cols) = m.shape();
+        let mut data = Vec::with_capacity(rows * cols);
+        for j in 0..cols {
+            for i in 0..rows {
+                data.push(m.get(i, j).unwrap().clone());
+            }
+        }
+        Self { data, rows, cols }
+    }
+}
+
+impl<T> ColCache<T> {
+    /// Returns the column `j` as a slice, or `None` if it is out of bounds.
+    #[inline]
+    pub fn col_slice(&self, j: usize) -> Option<&[T]> {
+        if j >= self.cols {
+            return None
+        }
+        Some(&self.data[j * self.rows..(j + 1) * self.rows])
+    }
+
+    /// Returns the column `j` as a mutable slice, or `None` if it is out of bounds.
+    #[inline]
+    pub fn col_slice_mut(&mut self, j: usize) -> Option<&mut [T]> {
+        if j >= self.cols {
+            return None
+        }
+        Some(&mut self.data[j * self.rows..(j + 1) * self.rows])
+    }
+}
+
+impl<T> MatrixExt for ColCache<T> {
+    type Element = T;
+
+    #[inline]
+    fn num_rows(&self) -> usize { self.rows }
+
+    #[inline]
+    fn num_cols(&self) -> usize { self.cols }
+
+    #[inline]
+    fn get(&self, row: usize, column: usize) -> Option<&Self::Element> {
+        if row >= self.rows || column >= self.cols {
+            return None
+        }
+        self.data.get(column * self.rows + row)
+    }
+}
+
+impl<T> MatrixMutExt for ColCache<T> {
+    #[inline]
+    fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut Self::Element> {
+        if row >= self.rows || column >= self.cols {
+            return None
+        }
+        self.data.get_mut(column * self.rows + row)
+    }
+}
+
+
+/// A grid storing only the elements which have been set, in a `HashMap`, and sharing a default element for the others.
+///
+/// This suits very sparse grids of any size.
+///
+/// # Mutable access
+/// Accessing mutably a position holding the default element stores a clone of it there,
+/// so that every method of [`MatrixMutExt`] works on the whole grid. Prefer [`stored_mut`](HashGrid::stored_mut)
+/// to modify only the stored elements, since [`iter_mut`](MatrixMutExt::iter_mut) stores all of them.
+///
+/// # Example
+/// ```rust
+/// use matrixable::prelude::*;
+/// use matrixable::matrices::HashGrid;
+///
+/// let mut grid = HashGrid::new(1_000_000, 1_000_000, '.');
+///
+/// grid.set((3, 999_999), '#').unwrap();
+///
+/// assert_eq!(Some(&'#'), grid.get(3, 999_999));
+/// assert_eq!(Some(&'.'), grid.get(999_999, 3));
+/// assert_eq!(None, grid.get(1_000_000, 0));
+/// assert_eq!(1, grid.num_stored());
+///
+/// // Mutable access stores the default element.
+/// grid.modify((999_999, 3), |c| *c = '@').unwrap();
+/// assert_eq!(Some(&'@'), grid.get(999_999, 3));
+/// assert_eq!(2, grid.num_stored());
+///
+/// let mut small = HashGrid::new(2, 2, 0);
+/// small.iter_mut().zip(1..).for_each(|(x, n)| *x = n);
+/// small.swap((0, 0), (1, 1));
+/// assert_eq!(vec![4, 2, 3, 1], small.iter().copied().collect::<Vec<_>>());
+/// assert_eq!(4, small.num_stored());
+///
+/// // Strategies work as with any other matrix.
+/// let t = grid.access(Transpose);
+/// assert_eq!(Some(&'#'), t.get(999_999, 3));
+///
+/// // Counting without looking up twice.
+/// let mut adjacency = HashGrid::new(3, 3, 0);
+/// for edge in [(0, 1), (1, 2), (0, 1)] {
+///     *adjacency.entry(edge).unwrap().or_default() += 1;
+/// }
+/// assert_eq!(Some(&2), adjacency.get(0, 1));
+/// assert_eq!(2, adjacency.num_stored());
+/// assert!(adjacency.entry((3, 0)).is_none());
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct HashGrid<T> {
+    // Boxed so that elements do not move when the map grows.
+    cells: HashMap<(usize, usize), Box<T>>,
+    rows: usize,
+    cols: usize,
+    default: T,
+}
+
+impl<T> HashGrid<T> {
+    /// Creates a grid of `rows` rows and `cols` columns whose elements are all equal to `default`.
+    #[inline]
+    pub fn new(rows: usize, cols: usize, default: T) -> Self {
+        Self { cells: HashMap::new(), rows, cols, default }
+    }
+
+    /// Returns the element shared by all positions which are not stored.
+    #[inline]
+    pub fn default_element(&self) -> &T { &self.default }
+
+    /// Returns the number of elements stored.
+    #[inline]
+    pub fn num_stored(&self) -> usize { self.cells.len() }
+
+    /// Removes the element stored at the given position, which gets back the default element.
+    #[inline]
+    pub fn remove(&mut self, subscripts: (usize, usize)) -> Option<T> {
+        self.cells.remove(&subscripts).map(|val| *val)
+    }
+
+    /// Returns an iterator over the stored elements and their subscripts, in no particular order.
+    #[inline]
+    pub fn stored(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.cells.iter().map(|(&pos, val)| (pos, &**val))
+    }
+
+    /// Returns an iterator over the stored elements (mutable) and their subscripts, in no particular order.
+    #[inline]
+    pub fn stored_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> + '_ {
+        self.cells.iter_mut().map(|(&pos, val)| (pos, &mut **val))
+    }
+
+    /// Returns the entry of the given position, for in-place manipulation, or `None` if it is out of bounds.
+    #[inline]
+    pub fn entry(&mut self, subscripts: (usize, usize)) -> Option<GridEntry<'_, T>> {
+        if subscripts.0 >= self.rows || subscripts.1 >= self.cols {
+            return None
+        }
+        Some(GridEntry { entry: self.cells.entry(subscripts), default: &self.default })
+    }
+}
+
+impl<T> MatrixExt for HashGrid<T> {
+    type Element = T;
+
+    #[inline]
+    fn num_rows(&self) -> usize { self.rows }
+
+    #[inline]
+    fn num_cols(&self) -> usize { self.cols }
+
+    #[inline]
+    fn get(&self, row: usize, column: usize) -> Option<&Self::Element> {
+        if row >= self.rows || column >= self.cols {
+            return None
+        }
+        Some(self.cells.get(&(row, column)).map_or(&self.default, |val| val))
+    }
+}
+
+impl<T: Clone> MatrixMutExt for HashGrid<T> {
+    /// Returns the element at the given position, or `None` if it is out of bounds.
+    /// A clone of the default element is stored first if no element was stored there.
+    #[inline]
+    fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut Self::Element> {
+        if row >= self.rows || column >= self.cols {
+            return None
+        }
+        let default = &self.default;
+        Some(self.cells.entry((row, column)).or_insert_with(|| Box::new(default.clone())))
+    }
+
+    /// Stores `val` at the given position, whether an element was stored there or not.
+    #[inline]
+    fn set(&mut self, subscripts: (usize, usize), val: Self::Element) -> Result<(), &'static str> {
+        if subscripts.0 >= self.rows || subscripts.1 >= self.cols {
+            return Err("Cannot access element from indexes.")
+        }
+        self.cells.insert(subscripts, Box::new(val));
+        Ok(())
+    }
+
+    /// Stores `val` at the `n`-th position, whether an element was stored there or not.
+    #[inline]
+    fn set_nth(&mut self, n: usize, val: Self::Element) -> Result<(), &'static str> {
+        let subscripts = self.subscripts_from(n);
+        self.set(subscripts, val).map_err(|_| "Cannot access element from index.")
+    }
+}
+
+/// An entry of a [`HashGrid`], stored or not, returned by [`HashGrid::entry`].
+#[derive(Debug)]
+pub struct GridEntry<'a, T> {
+    entry: hash_map::Entry<'a, (usize, usize), Box<T>>,
+    default: &'a T,
+}
+
+impl<'a, T> GridEntry<'a, T> {
+    /// Returns the subscripts of the entry.
+    #[inline]
+    pub fn key(&self) -> &(usize, usize) { self.entry.key() }
+
+    /// Stores `val` if no element is stored, and returns the element stored.
+    #[inline]
+    pub fn or_insert(self, val: T) -> &'a mut T {
+        self.entry.or_insert_with(|| Box::new(val))
+    }
+
+    /// Stores the result of `f` if no element is stored, and returns the element stored.
+    #[inline]
+    pub fn or_insert_with<F: FnOnce() -> T>(self, f: F) -> &'a mut T {
+        self.entry.or_insert_with(|| Box::new(f()))
+    }
+
+    /// Modifies the element if it is stored.
+    #[inline]
+    pub fn and_modify<F: FnOnce(&mut T)>(self, f: F) -> Self {
+        Self { entry: self.entry.and_modify(|val| f(val)), default: self.default }
+    }
+}
+
+impl<'a, T: Clone> GridEntry<'a, T> {
+    /// Stores a clone of the default element of the grid if no element is stored, and returns the element stored.
+    #[inline]
+    pub fn or_default(self) -> &'a mut T {
+        let default = self.default;
+        self.entry.or_insert_with(|| Box::new(default.clone()))
+    }
+}
+
+
+/// A sparse matrix storing only the elements which have been set, ordered by their position in *Row Major Order*,
+/// and sharing a default element for the others.
+///
+/// Unlike [`HashGrid`], stored elements can be visited in order, row by row, without visiting the default ones.
+///
+/// # Mutable access
+/// Accessing mutably a position holding the default element stores a clone of it there,
+/// so that every method of [`MatrixMutExt`] works on the whole matrix. Prefer [`occupied_mut`](SparseMatrix::occupied_mut)
+/// to modify only the stored elements, since [`iter_mut`](MatrixMutExt::iter_mut) stores all of them.
+///
+/// # Example
+/// ```rust
+/// use matrixable::prelude::*;
+/// use matrixable::matrices::SparseMatrix;
+///
+/// let mut m = SparseMatrix::new(1000, 1000, 0);
+///
+/// m.set((500, 2), 3).unwrap();
+/// m.set((1, 999), 2).unwrap();
+/// m.set((1, 4), 1).unwrap();
+///
+/// assert_eq!(
+///     vec![((1, 4), &1), ((1, 999), &2), ((500, 2), &3)],
+///     m.occupied().collect::<Vec<_>>()
+/// );
+/// assert_eq!(vec![((1, 4), &1), ((1, 999), &2)], m.occupied_row(1).collect::<Vec<_>>());
+/// assert_eq!(Some(&0), m.get(2, 2));
+///
+/// m.occupied_mut().for_each(|(_, x)| *x *= 10);
+/// assert_eq!(Some(&30), m.get(500, 2));
+///
+/// // Mutable access stores the default element.
+/// m.modify((2, 2), |x| *x += 1).unwrap();
+/// assert_eq!(Some(&1), m.get(2, 2));
+/// assert_eq!(4, m.num_stored());
+///
+/// // Diagonals are indexed as in `diag`.
+/// assert_eq!(vec![((1, 4), &10)], m.occupied_diag(1002).collect::<Vec<_>>());
+///
+/// let mut small = SparseMatrix::new(2, 2, 0);
+/// small.iter_mut().zip(1..).for_each(|(x, n)| *x = n);
+/// small.swap((0, 0), (1, 1));
+/// assert_eq!(vec![4, 2, 3, 1], small.iter().copied().collect::<Vec<_>>());
+///
+/// // Counting without looking up twice.
+/// let mut adjacency = SparseMatrix::new(3, 3, 0);
+/// for edge in [(0, 1), (1, 2), (0, 1)] {
+///     adjacency.entry(edge).unwrap().and_modify(|n| *n += 1).or_insert(1);
+/// }
+/// assert_eq!(vec![((0, 1), &2), ((1, 2), &1)], adjacency.occupied().collect::<Vec<_>>());
+/// assert!(adjacency.entry((0, 3)).is_none());
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Hash, PartialEq, Eq, Clone, Debug)]
+pub struct SparseMatrix<T> {
+    // Boxed so that elements do not move when the map is rebalanced.
+    cells: BTreeMap<(usize, usize), Box<T>>,
+    rows: usize,
+    cols: usize,
+    default: T,
+}
+
+impl<T> SparseMatrix<T> {
+    /// Creates a sparse matrix of `rows` rows and `cols` columns whose elements are all equal to `default`.
+    #[inline]
+    pub fn new(rows: usize, cols: usize, default: T) -> Self {
+        Self { cells: BTreeMap::new(), rows, cols, default }
+    }
+
+    /// Returns the element shared by all positions which are not stored.
+    #[inline]
+    pub fn default_element(&self) -> &T { &self.default }
+
+    /// Returns the number of elements stored.
+    #[inline]
+    pub fn num_stored(&self) -> usize { self.cells.len() }
+
+    /// Removes the element stored at the given position, which gets back the default element.
+    #[inline]
+    pub fn remove(&mut self, subscripts: (usize, usize)) -> Option<T> {
+        self.cells.remove(&subscripts).map(|val| *val)
+    }
+
+    /// Returns an iterator over the stored elements and their subscripts, in *Row Major Order*.
+    #[inline]
+    pub fn occupied(&self) -> impl DoubleEndedIterator<Item = ((usize, usize), &T)> + '_ {
+        self.cells.iter().map(|(&pos, val)| (pos, &**val))
+    }
+
+    /// Returns an iterator over the stored elements of the `i`-th row and their subscripts, in order.
+    #[inline]
+    pub fn occupied_row(&self, i: usize) -> impl DoubleEndedIterator<Item = ((usize, usize), &T)> + '_ {
+        self.cells.range((i, 0)..(i.saturating_add(1), 0)).map(|(&pos, val)| (pos, &**val))
+    }
+
+    /// Returns an iterator over the stored elements of the `j`-th column and their subscripts, in order.
+    #[inline]
+    pub fn occupied_col(&self, j: usize) -> impl DoubleEndedIterator<Item = ((usize, usize), &T)> + '_ {
+        self.occupied().filter(move |&((_, col), _)| col == j)
+    }
+
+    /// Returns an iterator over the stored elements of the `n`-th diagonal and their subscripts, in order.
+    ///
+    /// Diagonals are indexed as in [`diag`](MatrixExt::diag), from the bottom-left corner.
+    #[inline]
+    pub fn occupied_diag(&self, n: usize) -> impl DoubleEndedIterator<Item = ((usize, usize), &T)> + '_ {
+        let len = self.diag_len(n);
+        // First position of the diagonal.
+        let (i, j) = (self.rows.saturating_sub(n + 1), n.saturating_sub(self.rows.saturating_sub(1)));
+        (0..len).filter_map(move |k| {
+            self.cells.get(&(i + k, j + k)).map(|val| ((i + k, j + k), &**val))
+        })
+    }
+
+    /// Returns an iterator over the stored elements (mutable) and their subscripts, in *Row Major Order*.
+    #[inline]
+    pub fn occupied_mut(&mut self) -> impl DoubleEndedIterator<Item = ((usize, usize), &mut T)> + '_ {
+        self.cells.iter_mut().map(|(&pos, val)| (pos, &mut **val))
+    }
+
+    /// Returns the entry of the given position, for in-place manipulation, or `None` if it is out of bounds.
+    #[inline]
+    pub fn entry(&mut self, subscripts: (usize, usize)) -> Option<SparseEntry<'_, T>> {
+        if subscripts.0 >= self.rows || subscripts.1 >= self.cols {
+            return None
+        }
+        Some(SparseEntry { entry: self.cells.entry(subscripts), default: &self.default })
+    }
+}
+
+impl<T> MatrixExt for SparseMatrix<T> {
+    type Element = T;
+
+    #[inline]
+    fn num_rows(&self) -> usize { self.rows }
+
+    #[inline]
+    fn num_cols(&self) -> usize { self.cols }
+
+    #[inline]
+    fn get(&self, row: usize, column: usize) -> Option<&Self::Element> {
+        if row >= self.rows || column >= self.cols {
+            return None
+        }
+        Some(self.cells.get(&(row, column)).map_or(&self.default, |val| val))
+    }
+}
+
+impl<T: Clone> MatrixMutExt for SparseMatrix<T> {
+    /// Returns the element at the given position, or `None` if it is out of bounds.
+    /// A clone of the default element is stored first if no element was stored there.
+    #[inline]
+    fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut Self::Element> {
+        if row >= self.rows || column >= self.cols {
+            return None
+        }
+        let default = &self.default;
+        Some(self.cells.entry((row, column)).or_insert_with(|| Box::new(default.clone())))
+    }
+
+    /// Stores `val` at the given position, whether an element was stored there or not.
+    #[inline]
+    fn set(&mut self, subscripts: (usize, usize), val: Self::Element) -> Result<(), &'static str> {
+        if subscripts.0 >= self.rows || subscripts.1 >= self.cols {
+            return Err("Cannot access element from indexes.")
+        }
+        self.cells.insert(subscripts, Box::new(val));
+        Ok(())
+    }
+
+    /// Stores `val` at the `n`-th position, whether an element was stored there or not.
+    #[inline]
+    fn set_nth(&mut self, n: usize, val: Self::Element) -> Result<(), &'static str> {
+        let subscripts = self.subscripts_from(n);
+        self.set(subscripts, val).map_err(|_| "Cannot access element from index.")
+    }
+}
+
+/// An entry of a [`SparseMatrix`], stored or not, returned by [`SparseMatrix::entry`].
+#[derive(Debug)]
+pub struct SparseEntry<'a, T> {
+    entry: btree_map::Entry<'a, (usize, usize), Box<T>>,
+    default: &'a T,
+}
+
+impl<'a, T> SparseEntry<'a, T> {
+    /// Returns the subscripts of the entry.
+    #[inline]
+    pub fn key(&self) -> &(usize, usize) { self.entry.key() }
+
+    /// Stores `val` if no element is stored, and returns the element stored.
+    #[inline]
+    pub fn or_insert(self, val: T) -> &'a mut T {
+        self.entry.or_insert_with(|| Box::new(val))
+    }
+
+    /// Stores the result of `f` if no element is stored, and returns the element stored.
+    #[inline]
+    pub fn or_insert_with<F: FnOnce() -> T>(self, f: F) -> &'a mut T {
+        self.entry.or_insert_with(|| Box::new(f()))
+    }
+
+    /// Modifies the element if it is stored.
+    #[inline]
+    pub fn and_modify<F: FnOnce(&mut T)>(self, f: F) -> Self {
+        Self { entry: self.entry.and_modify(|val| f(val)), default: self.default }
+    }
+}
+
+impl<'a, T: Clone> SparseEntry<'a, T> {
+    /// Stores a clone of the default element of the matrix if no element is stored, and returns the element stored.
+    #[inline]
+    pub fn or_default(self) -> &'a mut T {
+        let default = self.default;
+        self.entry.or_insert_with(|| Box::new(default.clone()))
+    }
+}
+
+
+/// A pool of buffers for temporary matrices, so that algorithms needing scratch matrices repeatedly
+/// (stencils, double buffering...) do not allocate each time.
+///
+/// Matrices lent by [`take`](MatrixPool::take) give their buffer back to the pool when dropped.
+/// The pool can lend several matrices at once.
+///
+/// # Example
+/// ```rust
+/// use matrixable::prelude::*;
+/// use matrixable::matrices::MatrixPool;
+///
+/// let pool = MatrixPool::new();
+/// let m = [[1, 2, 3], [4, 5, 6]];
+///
+/// for _ in 0..3 {
+///     let mut scratch = pool.take(3, 2, 0);
+///     m.transpose_into(&mut scratch).unwrap();
+///     assert_eq!(&[1, 4, 2, 5, 3, 6], scratch.as_flat_slice());
+/// }
+/// // The same buffer was used each time.
+/// assert_eq!(1, pool.available());
+///
+/// {
+///     // Double buffering.
+///     let front = pool.take(2, 2, 0);
+///     let back = pool.take(2, 2, 1);
+///     assert_eq!(0, pool.available());
+///     assert!(front.iter().all(|&x| x == 0));
+///     assert_eq!(Some(&1), back.get(1, 1));
+/// }
+/// assert_eq!(2, pool.available());
+///
+/// // The shape is kept even without elements.
+/// assert_eq!((3, 0), pool.take(3, 0, 0).shape());
+/// ```
+#[derive(Debug, Default)]
+pub struct MatrixPool<T> {
+    free: RefCell<Vec<Vec<T>>>,
+}
+
+impl<T> MatrixPool<T> {
+    /// Creates an empty pool.
+    #[inline]
+    pub fn new() -> Self {
+        Self { free: RefCell::new(Vec::new()) }
+    }
+
+    /// Lends a `rows` x `cols` matrix filled with `value`, reusing a buffer of the pool if there is one.
+    pub fn take(&self, rows: usize, cols: usize, value: T) -> PooledMatrix<'_, T>
+    where T: Clone
+    {
+        let mut data = self.free.borrow_mut().pop().unwrap_or_default();
+        data.clear();
+        data.resize(rows * cols, value);
+        PooledMatrix { pool: self, data, rows, cols }
+    }
+
+    /// Returns the number of buffers waiting to be reused.
+    #[inline]
+    pub fn available(&self) -> usize { self.free.borrow().len() }
+
+    /// Frees the memory of the buffers waiting to be reused.
+    #[inline]
+    pub fn shrink(&self) { self.free.borrow_mut().clear() }
+}
+
+
+/// A matrix lent by a [`MatrixPool`], storing its elements in *Row Major Order*.
+///
+/// Its buffer goes back to the pool when it is dropped.
+#[derive(Debug)]
+pub struct PooledMatrix<'a, T> {
+    pool: &'a MatrixPool<T>,
+    data: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<'a, T> Drop for PooledMatrix<'a, T> {
+    fn drop(&mut self) {
+        self.pool.free.borrow_mut().push(::core::mem::take(&mut self.data));
+    }
+}
+
+impl<'a, T> MatrixExt for PooledMatrix<'a, T> {
+    type Element = T;
+
+    #[inline]
+    fn num_rows(&self) -> usize { self.rows }
+
+    #[inline]
+    fn num_cols(&self) -> usize { self.cols }
+
+    #[inline]
+    fn get(&self, row: usize, column: usize) -> Option<&Self::Element> {
+        if row >= self.rows || column >= self.cols {
+            return None
+        }
+        self.data.get(row * self.cols + column)
+    }
+}
+
+impl<'a, T> MatrixMutExt for PooledMatrix<'a, T> {
+    #[inline]
+    fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut Self::Element> {
+        if row >= self.rows || column >= self.cols {
+            return None
+        }
+        self.data.get_mut(row * self.cols + column)
+    }
+}
+
+impl<'a, T> MatrixSlices for PooledMatrix<'a, T> {
+    #[inline]
+    fn as_flat_slice(&self) -> &[Self::Element] { &self.data }
+}
+
+impl<'a, T> MatrixSlicesMut for PooledMatrix<'a, T> {
+    #[inline]
+    fn as_flat_slice_mut(&mut self) -> &mut [Self::Element] { &mut self.data }
+}
+
+
+/// Builds a matrix row by row, checking that all the rows have the same length.
+///
+/// The number of columns is given by [`with_cols`](MatrixBuilder::with_cols) or by the first row pushed.
+///
+/// # Example
+/// ```rust
+/// use matrixable::matrices::MatrixBuilder;
+///
+/// let mut builder = MatrixBuilder::new();
+///
+/// for line in ["1 2 3", "4 5 6"] {
+///     builder.push_row(line.split(' ').map(|x| x.parse::<i32>().unwrap())).unwrap();
+/// }
+/// assert_eq!(Err("Cannot push a row of a different length."), builder.push_row([7, 8]));
+/// assert_eq!((2, Some(3)), (builder.num_rows(), builder.num_cols()));
+///
+/// let m: [[i32; 3]; 2] = builder.finish();
+/// assert_eq!([[1, 2, 3], [4, 5, 6]], m);
+/// ```
+#[derive(Hash, PartialEq, Eq, Clone, Debug)]
+pub struct MatrixBuilder<T> {
+    data: Vec<T>,
+    rows: usize,
+    cols: Option<usize>,
+}
+
+impl<T> Default for MatrixBuilder<T> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+
+impl<T> MatrixBuilder<T> {
+    /// Creates a builder whose number of columns is given by the first row pushed.
+    #[inline]
+    pub fn new() -> Self {
+        Self { data: Vec::new(), rows: 0, cols: None }
+    }
+
+    /// Creates a builder for a matrix of `cols` columns.
+    #[inline]
+    pub fn with_cols(cols: usize) -> Self {
+        Self { data: Vec::new(), rows: 0, cols: Some(cols) }
+    }
+
+    /// Returns the number of rows pushed so far.
+    #[inline]
+    pub fn num_rows(&self) -> usize { self.rows }
+
+    /// Returns the number of columns, if it is known yet.
+    #[inline]
+    pub fn num_cols(&self) -> Option<usize> { self.cols }
+
+    /// Appends a row to the matrix.
+    ///
+    /// # Error
+    /// An error is returned, and the builder is left unchanged, if the row does not have the expected length.
+    pub fn push_row<I: IntoIterator<Item = T>>(&mut self, row: I) -> Result<(), &'static str> {
+        let start = self.data.len();
+        self.data.extend(row);
+        let len = self.data.len() - start;
+        match self.cols {
+            Some(cols) if cols != len => {
+                self.data.truncate(start);
+                Err("Cannot push a row of a different length.")
+            }
+            Some(_) => {
+                self.rows += 1;
+                Ok(())
+            }
+            None => {
+                self.cols = Some(len);
+                self.rows += 1;
+                Ok(())
+            }
+        }
+    }
+
+    /// Creates the matrix from the rows pushed.
+    #[inline]
+    pub fn finish<O: MatrixExtFromIter<T>>(self) -> O {
+        O::from_iter(self.data, self.cols.unwrap_or(0))
+    }
+
+    /// Same as [`finish`](Self::finish), but returns an error if the rows pushed cannot make a matrix of type `O`.
+    #[inline]
+    pub fn try_finish<O: MatrixExtFromIter<T>>(self) -> Result<O, &'static str> {
+        O::try_from_iter(self.data, self.cols.unwrap_or(0))
+    }
+}
+
+
+/// A read-only matrix viewing the contents of a memory-mapped file as elements in *Row Major Order*,
+/// so that matrices larger than the memory can be processed.
+///
+/// The whole mapping holds the elements, without any header, and its length must be a multiple of the size of a row.
+///
+/// # Example
+/// ```rust
+/// use matrixable::MatrixExt;
+/// use matrixable::matrices::MmapMatrix;
+///
+/// let path = std::env::temp_dir().join("matrixable_mmap_example.bin");
+/// let data: Vec<u8> = [1u32, 2, 3, 4, 5, 6].iter().flat_map(|x| x.to_ne_bytes()).collect();
+/// std::fs::write(&path, data).unwrap();
+///
+/// // SAFETY: The file is not modified while it is mapped.
+/// let m = unsafe { MmapMatrix::<u32>::open(&path, 3) }.unwrap();
+///
+/// assert_eq!((2, 3), m.shape());
+/// assert_eq!(Some(&6), m.get(1, 2));
+/// assert_eq!(21, m.iter().sum::<u32>());
+///
+/// # drop(m);
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+#[cfg(feature = "memmap")]
+#[derive(Debug)]
+pub struct MmapMatrix<T: bytemuck::Pod> {
+    mmap: memmap2::Mmap,
+    rows: usize,
+    cols: usize,
+    _marker: ::core::marker::PhantomData<T>,
+}
+
+#[cfg(feature = "memmap")]
+impl<T: bytemuck::Pod> MmapMatrix<T> {
+    /// Views an existing memory map as a matrix of `cols` columns.
+    ///
+    /// # Error
+    /// An error is returned if the mapping is not correctly aligned for `T`, or if its length is not
+    /// a multiple of the size of a row.
+    pub fn from_mmap(mmap: memmap2::Mmap, cols: usize) -> Result<Self, &'static str> {
+        let elements: &[T] = bytemuck::try_cast_slice(&mmap)
+            .map_err(|_| "Cannot view the mapping as a slice of elements.")?;
+        let rows = match elements.len().checked_div(cols) {
+            Some(rows) if rows * cols == elements.len() => rows,
+            None if elements.is_empty() => 0,
+            _ => return Err("Cannot split the mapping into rows of the given length."),
+        };
+        Ok(Self { mmap, rows, cols, _marker: ::core::marker::PhantomData })
+    }
+
+    /// Maps the file at `path` into memory and views it as a matrix of `cols` columns.
+    ///
+    /// # Error
+    /// An error is returned if the file cannot be opened or mapped, or under the conditions of [`from_mmap`](MmapMatrix::from_mmap)
+    /// (with the kind [`InvalidData`](std::io::ErrorKind::InvalidData)).
+    ///
+    /// # Safety
+    /// The file must not be modified, by this process or another one, while it is mapped.
+    /// See [`memmap2::Mmap::map`].
+    pub unsafe fn open<P: AsRef<std::path::Path>>(path: P, cols: usize) -> std::io::Result<Self> {
+        let file = std::fs::File::open(path)?;
+        // SAFETY: Guaranteed by the caller.
+        let mmap = unsafe { memmap2::Mmap::map(&file)? };
+        Self::from_mmap(mmap, cols).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+
+    /// Returns the underlying memory map.
+    #[inline]
+    pub fn mmap(&self) -> &memmap2::Mmap { &self.mmap }
+}
+
+#[cfg(feature = "memmap")]
+impl<T: bytemuck::Pod> MatrixExt for MmapMatrix<T> {
+    type Element = T;
+
+    #[inline]
+    fn num_rows(&self) -> usize { self.rows }
+
+    #[inline]
+    fn num_cols(&self) -> usize { self.cols }
+
+    #[inline]
+    fn get(&self, row: usize, column: usize) -> Option<&Self::Element> {
+        if row >= self.rows || column >= self.cols {
+            return None
+        }
+        self.as_flat_slice().get(row * self.cols + column)
+    }
+}
+
+#[cfg(feature = "memmap")]
+impl<T: bytemuck::Pod> MatrixSlices for MmapMatrix<T> {
+    #[inline]
+    fn as_flat_slice(&self) -> &[Self::Element] {
+        bytemuck::cast_slice(&self.mmap)
+    }
+}
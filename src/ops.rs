@@ -0,0 +1,438 @@
+//! Operations involving several matrices.
+//!
+//! Functions of this module work with any [`MatrixExt`] implementor, so arrays, `Access` views and
+//! user-defined matrices can be mixed as operands. When a new matrix is produced, its type is chosen by the
+//! caller through the [`MatrixExtFromIter`] trait.
+
+use ::core::fmt;
+use ::core::iter::Sum;
+use ::core::num::Wrapping;
+use ::core::ops::{ Add, Mul, Sub };
+
+use alloc::vec::Vec;
+
+use crate::MatrixExt;
+use crate::req::{ Float, MatrixExtFromIter };
+
+
+/// Performs the matrix product of `a` by `b`.
+///
+/// # Panics
+/// Panics if the number of columns of `a` is different from the number of rows of `b`.
+///
+/// # Example
+/// ```rust
+/// use matrixable::MatrixExt;
+/// use matrixable::ops::matmul;
+/// use matrixable::strategies::Transpose;
+///
+/// let a = [
+///     [1, 2, 3],
+///     [4, 5, 6]
+/// ];
+/// let b = [
+///     [ 7,  8],
+///     [ 9, 10],
+///     [11, 12]
+/// ];
+///
+/// let p: [[i32; 2]; 2] = matmul(&a, &b);
+/// assert_eq!([[58, 64], [139, 154]], p);
+///
+/// // Operands do not need to be of the same type.
+/// let t: [[i32; 2]; 2] = matmul(&b.access(Transpose), &a.access(Transpose));
+/// assert_eq!([[58, 139], [64, 154]], t);
+/// ```
+pub fn matmul<A, B, T, O>(a: &A, b: &B) -> O
+where
+    A: MatrixExt,
+    B: MatrixExt,
+    for<'x> &'x A::Element: Mul<&'x B::Element, Output = T>,
+    T: Sum<T>,
+    O: MatrixExtFromIter<T>,
+{
+    if a.num_cols() != b.num_rows() {
+        panic!("Cannot multiply a {:?} matrix by a {:?} matrix.", a.shape(), b.shape())
+    }
+
+    let (rows, cols) = (a.num_rows(), b.num_cols());
+    let mut v = Vec::with_capacity(rows * cols);
+    for i in 0..rows {
+        for j in 0..cols {
+            v.push(
+                a.row(i).unwrap()
+                 .zip(b.col(j).unwrap())
+                 .map(|(x, y)| x * y)
+                 .sum()
+            );
+        }
+    }
+
+    O::from_iter(v, cols)
+}
+
+/// Performs the product of the matrix `m` by the column vector `x`, giving `y = m·x`.
+///
+/// # Panics
+/// Panics if the length of `x` is different from the number of columns of `m`.
+///
+/// # Example
+/// ```rust
+/// use matrixable::MatrixExt;
+/// use matrixable::ops::mat_vec;
+/// use matrixable::strategies::Transpose;
+///
+/// let m = [
+///     [1, 2, 3],
+///     [4, 5, 6]
+/// ];
+///
+/// assert_eq!(vec![14, 32], mat_vec(&m, &[1, 2, 3]));
+/// assert_eq!(vec![9, 12, 15], mat_vec(&m.access(Transpose), &[1, 2]));
+/// ```
+pub fn mat_vec<A, U, T>(m: &A, x: &[U]) -> Vec<T>
+where
+    A: MatrixExt,
+    for<'x> &'x A::Element: Mul<&'x U, Output = T>,
+    T: Sum<T>,
+{
+    if m.num_cols() != x.len() {
+        panic!("Cannot multiply a {:?} matrix by a vector of length {}.", m.shape(), x.len())
+    }
+
+    m.rows()
+     .map(|row| row.zip(x).map(|(a, b)| a * b).sum())
+     .collect()
+}
+
+/// Performs the product of the row vector `x` by the matrix `m`, giving `y = x·m`.
+///
+/// # Panics
+/// Panics if the length of `x` is different from the number of rows of `m`.
+///
+/// # Example
+/// ```rust
+/// use matrixable::ops::vec_mat;
+///
+/// let m = [
+///     [1, 2, 3],
+///     [4, 5, 6]
+/// ];
+///
+/// assert_eq!(vec![9, 12, 15], vec_mat(&[1, 2], &m));
+/// ```
+pub fn vec_mat<B, U, T>(x: &[U], m: &B) -> Vec<T>
+where
+    B: MatrixExt,
+    for<'x> &'x U: Mul<&'x B::Element, Output = T>,
+    T: Sum<T>,
+{
+    if x.len() != m.num_rows() {
+        panic!("Cannot multiply a vector of length {} by a {:?} matrix.", x.len(), m.shape())
+    }
+
+    m.cols()
+     .map(|col| x.iter().zip(col).map(|(a, b)| a * b).sum())
+     .collect()
+}
+
+/// Solves the linear least squares problem `min ‖a·x - b‖` using a QR decomposition of `a`.
+///
+/// `b` may have several columns, each one being a right-hand side. Returns the solution `x`
+/// and the residual norm `‖a·x - b‖` (Frobenius norm when `b` has several columns),
+/// or `None` if the columns of `a` are linearly dependent.
+///
+/// # Panics
+/// Panics if `a` and `b` do not have the same number of rows or if `a` has more columns than rows.
+///
+/// # Example
+/// ```rust
+/// use matrixable::ops::lstsq;
+///
+/// // Fitting `y = c0 + c1 * x` through the points (0, 1), (1, 2), (2, 4).
+/// let a = [
+///     [1.0, 0.0],
+///     [1.0, 1.0],
+///     [1.0, 2.0]
+/// ];
+/// let b = [[1.0], [2.0], [4.0]];
+///
+/// let (c, residual): ([[f64; 1]; 2], f64) = lstsq(&a, &b).unwrap();
+///
+/// assert!((c[0][0] - 5.0 / 6.0).abs() < 1e-12);
+/// assert!((c[1][0] - 1.5).abs() < 1e-12);
+/// assert!((residual - (1.0f64 / 6.0).sqrt()).abs() < 1e-12);
+///
+/// // Columns are linearly dependent.
+/// assert_eq!(None, lstsq::<_, _, _, [[f64; 1]; 2]>(&[[1.0, 2.0], [2.0, 4.0]], &[[1.0], [2.0]]));
+/// ```
+pub fn lstsq<A, B, T, O>(a: &A, b: &B) -> Option<(O, T)>
+where
+    A: MatrixExt<Element = T>,
+    B: MatrixExt<Element = T>,
+    T: Float,
+    O: MatrixExtFromIter<T>,
+{
+    let (m, n) = a.shape();
+    let k = b.num_cols();
+    if b.num_rows() != m {
+        panic!("Cannot solve a system with a {:?} matrix and a {:?} right-hand side.", a.shape(), b.shape())
+    }
+    if n > m {
+        panic!("Cannot solve an underdetermined system with a {:?} matrix.", a.shape())
+    }
+
+    let mut r: Vec<T> = a.iter().copied().collect();
+    let mut y: Vec<T> = b.iter().copied().collect();
+
+    let scale = r.iter().fold(T::ZERO, |acc, &x| acc + x * x).sqrt();
+    let tolerance = T::EPSILON * T::from_usize(m) * scale;
+
+    // Householder reflections: `r` becomes upper triangular and `y` becomes `Qᵀ·b`.
+    let mut v = Vec::with_capacity(m);
+    for c in 0..n {
+        let norm = (c..m).fold(T::ZERO, |acc, i| acc + r[i * n + c] * r[i * n + c]).sqrt();
+        if norm <= tolerance {
+            return None
+        }
+        let alpha = if r[c * n + c] < T::ZERO { norm } else { -norm };
+
+        v.clear();
+        v.extend((c..m).map(|i| r[i * n + c]));
+        v[0] = v[0] - alpha;
+        let vv = v.iter().fold(T::ZERO, |acc, &x| acc + x * x);
+
+        let reflect = |data: &mut [T], width: usize, j: usize| {
+            let dot = (c..m).fold(T::ZERO, |acc, i| acc + v[i - c] * data[i * width + j]);
+            let f = (dot + dot) / vv;
+            (c..m).for_each(|i| data[i * width + j] = data[i * width + j] - f * v[i - c]);
+        };
+        (c + 1..n).for_each(|j| reflect(&mut r, n, j));
+        (0..k).for_each(|j| reflect(&mut y, k, j));
+
+        r[c * n + c] = alpha;
+    }
+
+    // Back substitution on `r·x = (Qᵀ·b)[..n]`.
+    let mut x = alloc::vec![T::ZERO; n * k];
+    for j in 0..k {
+        for i in (0..n).rev() {
+            let s = (i + 1..n).fold(y[i * k + j], |acc, l| acc - r[i * n + l] * x[l * k + j]);
+            x[i * k + j] = s / r[i * n + i];
+        }
+    }
+
+    let residual = y[n * k..].iter().fold(T::ZERO, |acc, &e| acc + e * e).sqrt();
+
+    Some((O::from_iter(x, k), residual))
+}
+
+
+/// Combines the elements of `a` and `b` at the same subscripts.
+fn elementwise<A, B, T, O>(a: &A, b: &B, f: impl Fn(&A::Element, &B::Element) -> T) -> O
+where
+    A: MatrixExt,
+    B: MatrixExt,
+    O: MatrixExtFromIter<T>,
+{
+    if a.shape() != b.shape() {
+        panic!("Cannot combine a {:?} matrix with a {:?} matrix element by element.", a.shape(), b.shape())
+    }
+
+    let (rows, cols) = a.shape();
+    let v: Vec<T> = (0..rows * cols)
+        .map(|n| f(a.get(n / cols, n % cols).unwrap(), b.get(n / cols, n % cols).unwrap()))
+        .collect();
+    O::from_iter(v, cols)
+}
+
+/// Adds `a` and `b` element by element, wrapping around at the boundary of the integer type.
+///
+/// # Panics
+/// Panics if `a` and `b` do not have the same shape.
+///
+/// # Example
+/// ```rust
+/// use matrixable::ops::wrapping_add;
+///
+/// let a: [[u8; 2]; 1] = [[250, 1]];
+/// let b: [[u8; 2]; 1] = [[10, 2]];
+///
+/// let s: [[u8; 2]; 1] = wrapping_add(&a, &b);
+/// assert_eq!([[4, 3]], s);
+/// ```
+pub fn wrapping_add<A, B, T, O>(a: &A, b: &B) -> O
+where
+    A: MatrixExt<Element = T>,
+    B: MatrixExt<Element = T>,
+    T: Copy,
+    Wrapping<T>: Add<Output = Wrapping<T>>,
+    O: MatrixExtFromIter<T>,
+{
+    elementwise(a, b, |&x, &y| (Wrapping(x) + Wrapping(y)).0)
+}
+
+/// Subtracts `b` from `a` element by element, wrapping around at the boundary of the integer type.
+///
+/// # Panics
+/// Panics if `a` and `b` do not have the same shape.
+///
+/// # Example
+/// ```rust
+/// use matrixable::ops::wrapping_sub;
+///
+/// let a: [[u8; 2]; 1] = [[0, 5]];
+/// let b: [[u8; 2]; 1] = [[1, 2]];
+///
+/// let d: [[u8; 2]; 1] = wrapping_sub(&a, &b);
+/// assert_eq!([[255, 3]], d);
+/// ```
+pub fn wrapping_sub<A, B, T, O>(a: &A, b: &B) -> O
+where
+    A: MatrixExt<Element = T>,
+    B: MatrixExt<Element = T>,
+    T: Copy,
+    Wrapping<T>: Sub<Output = Wrapping<T>>,
+    O: MatrixExtFromIter<T>,
+{
+    elementwise(a, b, |&x, &y| (Wrapping(x) - Wrapping(y)).0)
+}
+
+/// Multiplies `a` and `b` element by element (this is not the matrix product),
+/// wrapping around at the boundary of the integer type.
+///
+/// # Panics
+/// Panics if `a` and `b` do not have the same shape.
+///
+/// # Example
+/// ```rust
+/// use matrixable::ops::wrapping_mul;
+///
+/// let a: [[u32; 2]; 1] = [[0x9E37_79B9, 3]];
+/// let b: [[u32; 2]; 1] = [[16, 5]];
+///
+/// let p: [[u32; 2]; 1] = wrapping_mul(&a, &b);
+/// assert_eq!([[0xE377_9B90, 15]], p);
+/// ```
+pub fn wrapping_mul<A, B, T, O>(a: &A, b: &B) -> O
+where
+    A: MatrixExt<Element = T>,
+    B: MatrixExt<Element = T>,
+    T: Copy,
+    Wrapping<T>: Mul<Output = Wrapping<T>>,
+    O: MatrixExtFromIter<T>,
+{
+    elementwise(a, b, |&x, &y| (Wrapping(x) * Wrapping(y)).0)
+}
+
+
+/// Differences between two matrices, created by [`diff`].
+///
+/// It records the shapes of both matrices and the first mismatching elements in *Row Major Order*,
+/// along with the total number of mismatches. When shapes differ, only the elements at subscripts
+/// valid in both matrices are compared.
+///
+/// Its [`Display`](fmt::Display) implementation gives a readable report, which suits assertion messages.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MatrixDiff<'a, T, U> {
+    shapes: ((usize, usize), (usize, usize)),
+    mismatches: Vec<(usize, usize, &'a T, &'a U)>,
+    count: usize,
+}
+
+impl<'a, T, U> MatrixDiff<'a, T, U> {
+    /// Checks if the matrices have the same shape and the same elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.count == 0 && !self.shape_differs()
+    }
+
+    /// Checks if the matrices have different shapes.
+    #[inline]
+    pub fn shape_differs(&self) -> bool { self.shapes.0 != self.shapes.1 }
+
+    /// Returns the shapes of the first and second matrices.
+    #[inline]
+    pub fn shapes(&self) -> ((usize, usize), (usize, usize)) { self.shapes }
+
+    /// Returns the recorded mismatches as `(i, j, a[i][j], b[i][j])`, at most as many as the limit given to [`diff`].
+    #[inline]
+    pub fn mismatches(&self) -> &[(usize, usize, &'a T, &'a U)] { &self.mismatches }
+
+    /// Returns the total number of mismatching elements, including those which were not recorded.
+    #[inline]
+    pub fn count(&self) -> usize { self.count }
+}
+
+impl<'a, T: fmt::Debug, U: fmt::Debug> fmt::Display for MatrixDiff<'a, T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return f.write_str("matrices are equal")
+        }
+        if self.shape_differs() {
+            writeln!(f, "shapes differ: {:?} != {:?}", self.shapes.0, self.shapes.1)?;
+        }
+        writeln!(f, "{} mismatching element(s)", self.count)?;
+        for (i, j, a, b) in &self.mismatches {
+            writeln!(f, "  ({i}, {j}): {a:?} != {b:?}")?;
+        }
+        if self.count > self.mismatches.len() {
+            writeln!(f, "  ... and {} more", self.count - self.mismatches.len())?;
+        }
+        Ok(())
+    }
+}
+
+/// Compares two matrices element by element, recording at most `limit` mismatches.
+///
+/// # Example
+/// ```rust
+/// use matrixable::ops::diff;
+///
+/// let a = [[1, 2, 3], [4, 5, 6]];
+/// let b = [[1, 0, 3], [4, 5, 0]];
+///
+/// let d = diff(&a, &b, 1);
+///
+/// assert!(!d.is_empty());
+/// assert_eq!(2, d.count());
+/// assert_eq!(&[(0, 1, &2, &0)], d.mismatches());
+/// assert_eq!("\
+/// 2 mismatching element(s)
+///   (0, 1): 2 != 0
+///   ... and 1 more
+/// ", d.to_string());
+///
+/// let c = [[1, 2], [4, 5], [7, 8]];
+/// let d = diff(&a, &c, 10);
+///
+/// assert!(d.shape_differs());
+/// assert_eq!(0, d.count());
+///
+/// assert!(diff(&a, &a, 0).is_empty());
+/// ```
+pub fn diff<'a, A, B>(a: &'a A, b: &'a B, limit: usize) -> MatrixDiff<'a, A::Element, B::Element>
+where
+    A: MatrixExt,
+    B: MatrixExt,
+    A::Element: PartialEq<B::Element>,
+{
+    let rows = a.num_rows().min(b.num_rows());
+    let cols = a.num_cols().min(b.num_cols());
+
+    let mut mismatches = Vec::new();
+    let mut count = 0;
+    for i in 0..rows {
+        for j in 0..cols {
+            let (x, y) = (a.get(i, j).unwrap(), b.get(i, j).unwrap());
+            if x != y {
+                if count < limit {
+                    mismatches.push((i, j, x, y));
+                }
+                count += 1;
+            }
+        }
+    }
+
+    MatrixDiff { shapes: (a.shape(), b.shape()), mismatches, count }
+}
@@ -1,6 +1,12 @@
 //! Traits required for performing operations on `MatrixExt` structures are all packed here.
 
 
+use ::core::fmt;
+use ::core::marker::PhantomData;
+use ::core::ops::{ Add, Sub, Mul, Div, Neg };
+
+use alloc::boxed::Box;
+
 use crate::{ MatrixExt, MatrixMutExt };
 
 /// Exchange matrix dimensions.
@@ -12,16 +18,337 @@ pub trait SwapsDimensions: MatrixMutExt {
 }
 
 
+/// Matrices storing their elements contiguously in *Row Major Order*.
+///
+/// This gives access to rows as slices, for operations that are faster on slices (copies, sorts, ...).
+///
+/// # Example
+/// ```rust
+/// use matrixable::req::MatrixSlices;
+///
+/// let m = [[1, 2, 3], [4, 5, 6]];
+///
+/// assert_eq!(&[1, 2, 3, 4, 5, 6], m.as_flat_slice());
+/// assert_eq!(Some(&[4, 5, 6][..]), m.row_slice(1));
+/// assert_eq!(None, m.row_slice(2));
+/// ```
+pub trait MatrixSlices: MatrixExt {
+    /// Returns all the elements of the matrix in *Row Major Order*.
+    ///
+    /// The slice must contain exactly `num_rows() * num_cols()` elements.
+    fn as_flat_slice(&self) -> &[Self::Element];
+
+    /// Returns the `i`-th row of the matrix as a slice, or `None` if `i` is out of bound.
+    #[inline]
+    fn row_slice(&self, i: usize) -> Option<&[Self::Element]> {
+        if i >= self.num_rows() {
+            return None
+        }
+        let cols = self.num_cols();
+        self.as_flat_slice().get(i * cols..(i + 1) * cols)
+    }
+}
+
+/// Mutable counterpart of [`MatrixSlices`].
+///
+/// # Example
+/// ```rust
+/// use matrixable::req::MatrixSlicesMut;
+///
+/// let mut m = [[3, 1, 2], [6, 5, 4]];
+///
+/// m.row_slice_mut(1).unwrap().sort();
+/// m.as_flat_slice_mut()[0] = 0;
+///
+/// assert_eq!([[0, 1, 2], [4, 5, 6]], m);
+/// ```
+pub trait MatrixSlicesMut: MatrixSlices + MatrixMutExt {
+    /// Returns all the elements of the matrix in *Row Major Order* as a mutable slice.
+    fn as_flat_slice_mut(&mut self) -> &mut [Self::Element];
+
+    /// Returns the `i`-th row of the matrix as a mutable slice, or `None` if `i` is out of bound.
+    #[inline]
+    fn row_slice_mut(&mut self, i: usize) -> Option<&mut [Self::Element]> {
+        if i >= self.num_rows() {
+            return None
+        }
+        let cols = self.num_cols();
+        self.as_flat_slice_mut().get_mut(i * cols..(i + 1) * cols)
+    }
+}
+
+
 /// Create a matrix from an iterator.
 pub trait MatrixExtFromIter<A> {
     fn from_iter<I>(into_iter: I, columns: usize) -> Self
         where I: IntoIterator<Item = A>,
              <I as IntoIterator>::IntoIter: ExactSizeIterator;
+
+    /// Same as [`from_iter`](Self::from_iter), but returns an error instead of panicking
+    /// when the matrix cannot be built from the elements given.
+    ///
+    /// The default implementation calls `from_iter`, which is fine for matrices accepting any shape.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::req::MatrixExtFromIter;
+    ///
+    /// assert_eq!(Ok([[1, 2], [3, 4]]), <[[i32; 2]; 2]>::try_from_iter([1, 2, 3, 4], 2));
+    /// assert!(<[[i32; 2]; 2]>::try_from_iter([1, 2], 2).is_err());
+    /// ```
+    fn try_from_iter<I>(into_iter: I, columns: usize) -> Result<Self, &'static str>
+        where Self: Sized,
+             I: IntoIterator<Item = A>,
+             <I as IntoIterator>::IntoIter: ExactSizeIterator
+    {
+        Ok(Self::from_iter(into_iter, columns))
+    }
+
+    /// Creates a matrix filled with `0, 1, 2, ...` in *Row Major Order*.
+    ///
+    /// # Panics
+    /// Panics if a value does not fit in the element type.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::req::MatrixExtFromIter;
+    ///
+    /// let m = <[[u8; 3]; 2]>::iota(2, 3);
+    /// assert_eq!([[0, 1, 2], [3, 4, 5]], m);
+    /// ```
+    fn iota(rows: usize, cols: usize) -> Self
+    where
+        Self: Sized,
+        A: TryFrom<usize>
+    {
+        Self::from_iter((0..rows * cols).map(element_from), cols)
+    }
+
+    /// Creates a matrix alternating `a` and `b` like a checkerboard, starting with `a`.
+    ///
+    /// Elements equal to `a` are located by [`black_cells`](crate::MatrixExt::black_cells).
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::req::MatrixExtFromIter;
+    ///
+    /// let m = <[[char; 3]; 3]>::checkerboard(3, 3, '#', '.');
+    /// assert_eq!([
+    ///     ['#', '.', '#'],
+    ///     ['.', '#', '.'],
+    ///     ['#', '.', '#']
+    /// ], m);
+    /// ```
+    fn checkerboard(rows: usize, cols: usize, a: A, b: A) -> Self
+    where
+        Self: Sized,
+        A: Clone
+    {
+        let iter = (0..rows * cols).map(|n| {
+            if (n / cols + n % cols).is_multiple_of(2) { a.clone() } else { b.clone() }
+        });
+        Self::from_iter(iter, cols)
+    }
+
+    /// Creates a `n` x `n` magic square, containing the numbers from `1` to `n²` such that
+    /// all rows, columns and both main diagonals have the same sum.
+    ///
+    /// # Panics
+    /// Panics if `n == 2` (no such square exists) or if a value does not fit in the element type.
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::req::MatrixExtFromIter;
+    ///
+    /// let m = <[[u32; 3]; 3]>::magic(3);
+    /// assert_eq!([
+    ///     [8, 1, 6],
+    ///     [3, 5, 7],
+    ///     [4, 9, 2]
+    /// ], m);
+    ///
+    /// let m = <[[u32; 6]; 6]>::magic(6);
+    /// assert!(m.iter().all(|row| row.iter().sum::<u32>() == 111));
+    /// ```
+    fn magic(n: usize) -> Self
+    where
+        Self: Sized,
+        A: TryFrom<usize>
+    {
+        Self::from_iter(magic_square(n).into_iter().map(element_from), n)
+    }
+
+    /// Creates a block diagonal matrix, placing the matrices of `blocks` one after the other along the
+    /// diagonal and filling the rest with the default value of the elements (zero for numbers).
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::req::MatrixExtFromIter;
+    /// use matrixable::matrices::ConstantMatrix;
+    ///
+    /// let a = [[1, 2], [3, 4]];
+    /// let b = [[5, 6], [7, 8]];
+    ///
+    /// let m = <[[i32; 4]; 4]>::block_diag([a, b]);
+    /// assert_eq!([
+    ///     [1, 2, 0, 0],
+    ///     [3, 4, 0, 0],
+    ///     [0, 0, 5, 6],
+    ///     [0, 0, 7, 8],
+    /// ], m);
+    ///
+    /// // Blocks do not need to be square.
+    /// let blocks = [ConstantMatrix::new(1, 1, 2), ConstantMatrix::new(2, 2, 1)];
+    /// let m = <[[u8; 3]; 3]>::block_diag(blocks);
+    /// assert_eq!([
+    ///     [1, 1, 0],
+    ///     [0, 0, 2],
+    ///     [0, 0, 2],
+    /// ], m);
+    /// ```
+    fn block_diag<M, I>(blocks: I) -> Self
+    where
+        Self: Sized,
+        I: IntoIterator<Item = M>,
+        M: MatrixExt<Element = A>,
+        A: Clone + Default
+    {
+        let blocks: alloc::vec::Vec<M> = blocks.into_iter().collect();
+        let rows = blocks.iter().map(|b| b.num_rows()).sum::<usize>();
+        let cols = blocks.iter().map(|b| b.num_cols()).sum::<usize>();
+
+        let mut v = alloc::vec![A::default(); rows * cols];
+        let (mut i0, mut j0) = (0, 0);
+        for b in &blocks {
+            for i in 0..b.num_rows() {
+                for j in 0..b.num_cols() {
+                    v[(i0 + i) * cols + j0 + j] = b.get(i, j).unwrap().clone();
+                }
+            }
+            i0 += b.num_rows();
+            j0 += b.num_cols();
+        }
+        Self::from_iter(v, cols)
+    }
+
+    /// Creates a matrix by reading one row per line from `reader`, each line being turned into
+    /// the elements of a row by `parser`. Empty lines are skipped.
+    ///
+    /// Lines are read one at a time into the same buffer, so the input is never loaded as a whole.
+    ///
+    /// # Error
+    /// An error is returned if reading fails, or with the kind [`InvalidData`](std::io::ErrorKind::InvalidData)
+    /// if `parser` fails, if a row does not have the same length as the first one or if the rows read
+    /// cannot make a matrix of this type (see [`try_from_iter`](Self::try_from_iter)).
+    ///
+    /// # Example
+    /// ```rust
+    /// use matrixable::req::MatrixExtFromIter;
+    ///
+    /// let parse = |line: &str| line.split_whitespace().map(str::parse).collect::<Result<Vec<i32>, _>>();
+    ///
+    /// let input = "1 2 3\n\n4 5 6\n";
+    /// let m = <[[i32; 3]; 2]>::read_rows(input.as_bytes(), parse).unwrap();
+    /// assert_eq!([[1, 2, 3], [4, 5, 6]], m);
+    ///
+    /// let err = <[[i32; 3]; 2]>::read_rows("1 2 3\n4 x 6".as_bytes(), parse).unwrap_err();
+    /// assert_eq!(std::io::ErrorKind::InvalidData, err.kind());
+    ///
+    /// let err = <[[i32; 3]; 2]>::read_rows("1 2 3\n4 5".as_bytes(), parse).unwrap_err();
+    /// assert_eq!("Line 2: Cannot push a row of a different length.", err.to_string());
+    ///
+    /// // Too few or too many lines for the array.
+    /// let err = <[[i32; 3]; 2]>::read_rows("1 2 3\n".as_bytes(), parse).unwrap_err();
+    /// assert_eq!(std::io::ErrorKind::InvalidData, err.kind());
+    ///
+    /// let err = <[[i32; 3]; 2]>::read_rows("1 2 3\n4 5 6\n7 8 9\n".as_bytes(), parse).unwrap_err();
+    /// assert_eq!(std::io::ErrorKind::InvalidData, err.kind());
+    /// ```
+    fn read_rows<R, F, I, E>(mut reader: R, mut parser: F) -> std::io::Result<Self>
+    where
+        Self: Sized,
+        R: std::io::BufRead,
+        F: FnMut(&str) -> Result<I, E>,
+        I: IntoIterator<Item = A>,
+        E: Into<Box<dyn std::error::Error + Send + Sync>>
+    {
+        use std::io::{ Error, ErrorKind };
+
+        let mut builder = crate::matrices::MatrixBuilder::new();
+        let mut line = alloc::string::String::new();
+        let mut n = 0;
+        loop {
+            line.clear();
+            if reader.read_line(&mut line)? == 0 {
+                break
+            }
+            n += 1;
+            let content = line.trim_end_matches(['\n', '\r']);
+            if content.is_empty() {
+                continue
+            }
+            let row = parser(content).map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+            builder.push_row(row).map_err(|e| Error::new(ErrorKind::InvalidData, alloc::format!("Line {n}: {e}")))?;
+        }
+        builder.try_finish().map_err(|e| Error::new(ErrorKind::InvalidData, e))
+    }
+}
+
+/// Converts a number generated by a [`MatrixExtFromIter`] constructor into an element.
+fn element_from<A: TryFrom<usize>>(v: usize) -> A {
+    A::try_from(v).unwrap_or_else(|_| panic!("Value {v} does not fit in the element type."))
+}
+
+/// Numbers of a `n` x `n` magic square in *Row Major Order*, using the same constructions as MATLAB's `magic`.
+fn magic_square(n: usize) -> alloc::vec::Vec<usize> {
+    if n == 2 {
+        panic!("There is no magic square of size 2.")
+    }
+    if n % 2 == 1 {
+        // Siamese method.
+        return (0..n * n)
+            .map(|k| {
+                let (i, j) = (k / n, k % n);
+                n * ((i + j + 2 + n - (n + 3) / 2) % n) + (i + 2 * j + 1) % n + 1
+            })
+            .collect()
+    }
+    if n.is_multiple_of(4) {
+        // Numbers in order, except on the diagonals of each 4 x 4 block where they are reversed.
+        return (0..n * n)
+            .map(|k| {
+                let (i, j) = (k / n % 4, k % n % 4);
+                if i == j || i + j == 3 { n * n - k } else { k + 1 }
+            })
+            .collect()
+    }
+
+    // LUX method: four odd magic squares, then some columns are exchanged between the top and bottom halves.
+    let p = n / 2;
+    let sub = magic_square(p);
+    let offsets = [0, 2, 3, 1];
+    let mut m: alloc::vec::Vec<usize> = (0..n * n)
+        .map(|k| {
+            let (i, j) = (k / n, k % n);
+            sub[(i % p) * p + j % p] + offsets[2 * (i / p) + j / p] * p * p
+        })
+        .collect();
+    let k = (n - 2) / 4;
+    let mut exchange = |i: usize, j: usize| m.swap(i * n + j, (i + p) * n + j);
+    for i in 0..p {
+        for j in (0..k).chain(n - k + 1..n) {
+            exchange(i, j);
+        }
+    }
+    exchange(k, 0);
+    exchange(k, k);
+    m
 }
 
 
 /// A trait for in-place modification of matrices.
-/// The following example shows the implementation of the `SortBy` strategy used in this crate.
+/// The following example shows the implementation of a selection sort strategy,
+/// similar to [`SortBy::selection`](crate::strategies::SortBy::selection).
 ///
 /// # Example
 /// ```
@@ -94,4 +421,252 @@ pub trait AccessStrategy<M: MatrixExt> {
     
     /// Gives the number of columns for the `Access` matrix defined by the `AccessStrategy`.
     fn ncols(&self, m: &M) -> usize;
+
+    /// Returns a proof that the strategy implements [`TrustedAccess`], or `None` (the default).
+    ///
+    /// Strategies implementing `TrustedAccess` override it with `Some(Trusted::of(self))`,
+    /// allowing the `get_unchecked` method of [`Access`](crate::access::Access) and its siblings
+    /// to skip bound checking on `m`.
+    #[inline]
+    fn trusted(&self) -> Option<Trusted<M, Self>> where Self: Sized { None }
+}
+
+/// Access strategies which, given subscripts inside the `Access` matrix (`i < nrows(m)` and `j < ncols(m)`),
+/// never return from [`access`](AccessStrategy::access) a location outside `m`.
+///
+/// All strategies of this crate implement it, except [`AccessStrategySet`] whose strategies are only known at runtime.
+/// A strategy implementing it must also override [`AccessStrategy::trusted`] for the guarantee to be used.
+///
+/// # Safety
+///
+/// The `get_unchecked` method of [`Access`](crate::access::Access) and its siblings
+/// accesses `m` without bound checking for such strategies, so a wrong implementation is undefined behavior.
+///
+/// # Example
+/// ```rust
+/// use std::cell::Cell;
+/// use matrixable::MatrixExt;
+/// use matrixable::req::{ AccessStrategy, TrustedAccess, Trusted };
+/// use matrixable::strategies::AccessStrategySet;
+///
+/// struct Mirror;
+///
+/// impl<M: MatrixExt> AccessStrategy<M> for Mirror {
+///     fn access(&self, m: &M, i: usize, j: usize) -> Option<(usize, usize)> {
+///         Some((i, m.num_cols().checked_sub(j)?.checked_sub(1)?))
+///     }
+///     fn nrows(&self, m: &M) -> usize { m.num_rows() }
+///     fn ncols(&self, m: &M) -> usize { m.num_cols() }
+///
+///     fn trusted(&self) -> Option<Trusted<M, Self>> { Some(Trusted::of(self)) }
+/// }
+///
+/// // SAFETY: subscripts inside `m` are mapped inside `m`.
+/// unsafe impl<M: MatrixExt> TrustedAccess<M> for Mirror {}
+///
+/// let m = [[1, 2, 3], [4, 5, 6]];
+/// let access = m.access(Mirror);
+///
+/// assert_eq!(&4, unsafe { access.get_unchecked(1, 2) });
+///
+/// // A matrix counting the accesses checking its bounds.
+/// struct Counting([[i32; 3]; 2], Cell<usize>);
+///
+/// impl MatrixExt for Counting {
+///     type Element = i32;
+///     fn num_rows(&self) -> usize { 2 }
+///     fn num_cols(&self) -> usize { 3 }
+///     fn get(&self, i: usize, j: usize) -> Option<&i32> {
+///         self.1.set(self.1.get() + 1);
+///         self.0.get(i, j)
+///     }
+///     unsafe fn get_unchecked(&self, i: usize, j: usize) -> &i32 { self.0.get_unchecked(i, j) }
+/// }
+///
+/// let m = Counting([[1, 2, 3], [4, 5, 6]], Cell::new(0));
+///
+/// // The trusted strategy does not check the bounds of `m` again...
+/// assert_eq!(&4, unsafe { m.access(Mirror).get_unchecked(1, 2) });
+/// assert_eq!(0, m.1.get());
+///
+/// // ...unlike a strategy only known at runtime.
+/// let set: AccessStrategySet = vec![Box::new(Mirror)];
+/// assert_eq!(&4, unsafe { m.access(set).get_unchecked(1, 2) });
+/// assert_eq!(1, m.1.get());
+/// ```
+///
+/// [`AccessStrategySet`]: crate::strategies::AccessStrategySet
+pub unsafe trait TrustedAccess<M: MatrixExt>: AccessStrategy<M> {}
+
+/// A proof that the access strategy `S` implements [`TrustedAccess`], returned by [`AccessStrategy::trusted`].
+///
+/// Being tied to the type of the strategy, it cannot be borrowed from another strategy.
+pub struct Trusted<M, S>(PhantomData<fn(&M, &S)>);
+
+impl<M: MatrixExt, S: TrustedAccess<M>> Trusted<M, S> {
+    /// Creates the proof for a strategy implementing [`TrustedAccess`].
+    #[inline(always)]
+    pub fn of(_strategy: &S) -> Self { Self(PhantomData) }
+}
+
+impl<M, S> Trusted<M, S> {
+    /// A proof for `S` is also valid for references to `S`, which access elements the same way.
+    #[inline(always)]
+    pub(crate) fn by_ref<'a>(self) -> Trusted<M, &'a S> { Trusted(PhantomData) }
+}
+
+impl<M, S> fmt::Debug for Trusted<M, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Trusted")
+    }
+}
+
+
+/// Floating point numbers, required by numeric operations on matrices.
+///
+/// This trait is implemented for `f32` and `f64`.
+pub trait Float:
+    Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    /// Difference between `1` and the next representable number.
+    const EPSILON: Self;
+
+    /// Converts an integer to the nearest floating point number.
+    fn from_usize(n: usize) -> Self;
+
+    /// Returns the square root of the number.
+    fn sqrt(self) -> Self;
+
+    /// Returns the absolute value of the number.
+    fn abs(self) -> Self;
+}
+
+macro_rules! impl_float {
+    ($($t:ty)*) => {$(
+        impl Float for $t {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const EPSILON: Self = <$t>::EPSILON;
+
+            #[inline]
+            fn from_usize(n: usize) -> Self { n as $t }
+
+            #[inline]
+            fn sqrt(self) -> Self { <$t>::sqrt(self) }
+
+            #[inline]
+            fn abs(self) -> Self { <$t>::abs(self) }
+        }
+    )*};
+}
+
+impl_float! { f32 f64 }
+
+
+/// An object safe subset of [`MatrixExt`], allowing to mix different matrix types behind trait objects.
+///
+/// It is implemented for all `MatrixExt` implementors and `Box<dyn MatrixDyn>` implements `MatrixExt`,
+/// so boxed matrices get back all the methods of `MatrixExt`.
+///
+/// # Example
+/// ```rust
+/// use matrixable::MatrixExt;
+/// use matrixable::req::MatrixDyn;
+/// use matrixable::matrices::IdentityMatrix;
+///
+/// let matrices: Vec<Box<dyn MatrixDyn<Element = f64>>> = vec![
+///     Box::new([[1.0, 2.0], [3.0, 4.0]]),
+///     Box::new(IdentityMatrix::new(3, 1.0, 0.0)),
+/// ];
+///
+/// let sums: Vec<f64> = matrices.iter().map(|m| m.iter().sum()).collect();
+/// assert_eq!(vec![10.0, 3.0], sums);
+/// ```
+pub trait MatrixDyn {
+    type Element;
+
+    /// Same as [`MatrixExt::shape`].
+    fn dyn_shape(&self) -> (usize, usize);
+
+    /// Same as [`MatrixExt::get`].
+    fn dyn_get(&self, row: usize, column: usize) -> Option<&Self::Element>;
+}
+
+/// An object safe subset of [`MatrixMutExt`], allowing to mix different matrix types behind trait objects.
+///
+/// It is implemented for all `MatrixMutExt` implementors and `Box<dyn MatrixDynMut>` implements `MatrixMutExt`.
+///
+/// # Example
+/// ```rust
+/// use matrixable::MatrixMutExt;
+/// use matrixable::req::MatrixDynMut;
+///
+/// let mut matrices: Vec<Box<dyn MatrixDynMut<Element = i32>>> = vec![
+///     Box::new([[1, 2], [3, 4]]),
+///     Box::new([[5, 6, 7]]),
+/// ];
+///
+/// for m in matrices.iter_mut() {
+///     m.iter_mut().for_each(|x| *x *= 10);
+/// }
+///
+/// assert_eq!(Some(&70), matrices[1].dyn_get(0, 2));
+/// ```
+pub trait MatrixDynMut: MatrixDyn {
+    /// Same as [`MatrixMutExt::get_mut`].
+    fn dyn_get_mut(&mut self, row: usize, column: usize) -> Option<&mut Self::Element>;
+}
+
+impl<M: MatrixExt> MatrixDyn for M {
+    type Element = M::Element;
+
+    #[inline]
+    fn dyn_shape(&self) -> (usize, usize) { self.shape() }
+
+    #[inline]
+    fn dyn_get(&self, row: usize, column: usize) -> Option<&Self::Element> { self.get(row, column) }
+}
+
+impl<M: MatrixMutExt> MatrixDynMut for M {
+    #[inline]
+    fn dyn_get_mut(&mut self, row: usize, column: usize) -> Option<&mut Self::Element> { self.get_mut(row, column) }
+}
+
+impl<'a, T> MatrixExt for Box<dyn MatrixDyn<Element = T> + 'a> {
+    type Element = T;
+
+    #[inline]
+    fn num_rows(&self) -> usize { (**self).dyn_shape().0 }
+
+    #[inline]
+    fn num_cols(&self) -> usize { (**self).dyn_shape().1 }
+
+    #[inline]
+    fn get(&self, row: usize, column: usize) -> Option<&Self::Element> { (**self).dyn_get(row, column) }
+}
+
+impl<'a, T> MatrixExt for Box<dyn MatrixDynMut<Element = T> + 'a> {
+    type Element = T;
+
+    #[inline]
+    fn num_rows(&self) -> usize { (**self).dyn_shape().0 }
+
+    #[inline]
+    fn num_cols(&self) -> usize { (**self).dyn_shape().1 }
+
+    #[inline]
+    fn get(&self, row: usize, column: usize) -> Option<&Self::Element> { (**self).dyn_get(row, column) }
+}
+
+impl<'a, T> MatrixMutExt for Box<dyn MatrixDynMut<Element = T> + 'a> {
+    #[inline]
+    fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut Self::Element> { (**self).dyn_get_mut(row, column) }
 }
@@ -0,0 +1,954 @@
+//! Wrappers adding new behaviors to existing matrices.
+
+use ::core::cell::{ Cell, RefCell };
+use ::core::fmt;
+use ::core::ops::{ Deref, DerefMut };
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use std::sync::{ PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard };
+
+use crate::{ MatrixExt, MatrixMutExt };
+use crate::req::MatrixExtFromIter;
+
+
+/// A `MatrixExt` whose elements are the elements of a mutable slice seen as [`Cell`]s, in *Row Major Order*.
+///
+/// Since a `Cell` can be modified through a shared reference, several elements can be updated
+/// while the matrix is borrowed immutably (during an iteration for example).
+///
+/// It can also be created by the [`as_cells`](crate::MatrixMutExt::as_cells) method on `MatrixMutExt`
+/// for matrices with a contiguous storage.
+///
+/// # Example
+/// ```rust
+/// use matrixable::MatrixExt;
+/// use matrixable::wrappers::CellMatrix;
+///
+/// let mut m = [
+///     [1, 2, 3],
+///     [4, 5, 6],
+///     [7, 8, 9]
+/// ];
+///
+/// {
+///     let cells = CellMatrix::new(m.as_flattened_mut(), (3, 3));
+///
+///     // Swap each element with its symmetric while iterating.
+///     for (i, j, cell) in cells.enumerate() {
+///         if i < j {
+///             cell.swap(cells.get(j, i).unwrap());
+///         }
+///     }
+///
+///     cells.set((1, 1), 0).unwrap();
+/// }
+///
+/// assert_eq!([[1, 4, 7], [2, 0, 8], [3, 6, 9]], m);
+/// ```
+pub struct CellMatrix<'a, T> {
+    cells: &'a [Cell<T>],
+    rows: usize,
+    cols: usize,
+}
+
+impl<'a, T> CellMatrix<'a, T> {
+    /// Sees the elements of `slice` as a `rows` x `cols` matrix of `Cell`s.
+    ///
+    /// # Panics
+    /// Panics if the length of `slice` is not `rows * cols`.
+    pub fn new(slice: &'a mut [T], (rows, cols): (usize, usize)) -> Self {
+        if rows.checked_mul(cols) != Some(slice.len()) {
+            panic!("Cannot see {} elements as a {:?} matrix.", slice.len(), (rows, cols))
+        }
+        Self { cells: Cell::from_mut(slice).as_slice_of_cells(), rows, cols }
+    }
+
+    /// Changes the value of an element at the intersection of the `i`-th row and the `j`-th column of the matrix.
+    ///
+    /// # Error
+    /// An error is returned if any of those indexes are out of bounds.
+    #[inline]
+    pub fn set(&self, subscripts: (usize, usize), val: T) -> Result<(), &'static str> {
+        match self.get(subscripts.0, subscripts.1) {
+            Some(cell) => {
+                cell.set(val);
+                Ok(())
+            }
+            None => Err("Cannot access element from indexes."),
+        }
+    }
+}
+
+impl<'a, T> Clone for CellMatrix<'a, T> {
+    #[inline]
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, T> Copy for CellMatrix<'a, T> {}
+
+impl<'a, T: Copy + fmt::Debug> fmt::Debug for CellMatrix<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CellMatrix")
+            .field("cells", &self.cells)
+            .field("rows", &self.rows)
+            .field("cols", &self.cols)
+            .finish()
+    }
+}
+
+impl<'a, T> MatrixExt for CellMatrix<'a, T> {
+    type Element = Cell<T>;
+
+    #[inline]
+    fn num_rows(&self) -> usize { self.rows }
+
+    #[inline]
+    fn num_cols(&self) -> usize { self.cols }
+
+    #[inline]
+    fn get(&self, row: usize, column: usize) -> Option<&Self::Element> {
+        if row >= self.rows || column >= self.cols {
+            return None
+        }
+        self.cells.get(row * self.cols + column)
+    }
+}
+
+
+/// A wrapper implementing [`Display`](fmt::Display) for a matrix, printing one row per line with aligned columns.
+///
+/// The precision of the formatter (as in `{:.2}`) is applied to each element.
+///
+/// This `struct` is created by the [`display`](crate::MatrixExt::display) method on `MatrixExt`. See its documentation for more.
+#[derive(Debug)]
+pub struct MatrixDisplay<'a, M: MatrixExt> {
+    matrix: &'a M,
+}
+
+impl<'a, M: MatrixExt> MatrixDisplay<'a, M> {
+    pub(crate) fn new(matrix: &'a M) -> Self {
+        Self { matrix }
+    }
+}
+
+impl<'a, M: MatrixExt> Clone for MatrixDisplay<'a, M> {
+    #[inline]
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, M: MatrixExt> Copy for MatrixDisplay<'a, M> {}
+
+impl<'a, M> fmt::Display for MatrixDisplay<'a, M>
+where
+    M: MatrixExt,
+    M::Element: fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write;
+
+        let (rows, cols) = self.matrix.shape();
+        let mut cells: Vec<String> = Vec::with_capacity(rows * cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                let x = self.matrix.get(i, j).unwrap();
+                let mut cell = String::new();
+                match f.precision() {
+                    Some(p) => write!(cell, "{x:.p$}")?,
+                    None => write!(cell, "{x}")?,
+                }
+                cells.push(cell);
+            }
+        }
+
+        let widths: Vec<usize> = (0..cols)
+            .map(|j| (0..rows).map(|i| cells[i * cols + j].chars().count()).max().unwrap_or(0))
+            .collect();
+
+        for i in 0..rows {
+            if i > 0 {
+                f.write_char('\n')?;
+            }
+            for j in 0..cols {
+                if j > 0 {
+                    f.write_char(' ')?;
+                }
+                write!(f, "{:>width$}", cells[i * cols + j], width = widths[j])?;
+            }
+        }
+        Ok(())
+    }
+}
+
+
+/// A wrapper implementing [`Debug`](fmt::Debug) for a matrix by listing its rows, the way a 2D array would be printed.
+///
+/// This `struct` is created by the [`debug_resolved`](crate::access::Access::debug_resolved) method on `Access`
+/// and `AccessMut`. See its documentation for more.
+pub struct MatrixDebug<'a, M: MatrixExt> {
+    matrix: &'a M,
+}
+
+impl<'a, M: MatrixExt> MatrixDebug<'a, M> {
+    pub(crate) fn new(matrix: &'a M) -> Self {
+        Self { matrix }
+    }
+}
+
+impl<'a, M: MatrixExt> Clone for MatrixDebug<'a, M> {
+    #[inline]
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, M: MatrixExt> Copy for MatrixDebug<'a, M> {}
+
+impl<'a, M> fmt::Debug for MatrixDebug<'a, M>
+where
+    M: MatrixExt,
+    M::Element: fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        /// A single row of the matrix.
+        struct Row<'a, M: MatrixExt>(&'a M, usize);
+
+        impl<'a, M: MatrixExt> fmt::Debug for Row<'a, M> where M::Element: fmt::Debug {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let Row(m, i) = *self;
+                f.debug_list().entries((0..m.num_cols()).map(|j| m.get(i, j).unwrap())).finish()
+            }
+        }
+
+        f.debug_list().entries((0..self.matrix.num_rows()).map(|i| Row(self.matrix, i))).finish()
+    }
+}
+
+
+/// A `MatrixExt` alternating the rows (or the columns) of two matrices, without copying them.
+///
+/// Rows are taken one by one from each matrix, starting with the first one.
+/// When a matrix has more rows than the other, its last rows come after the interleaved ones.
+/// The same goes for columns.
+///
+/// # Example
+/// ```rust
+/// use matrixable::MatrixExt;
+/// use matrixable::wrappers::Interleaved;
+///
+/// let left = [[1, 2], [3, 4], [5, 6]];
+/// let right = [[-1, -2], [-3, -4]];
+///
+/// let rows = Interleaved::rows(&left, &right);
+/// assert_eq!(vec![
+///     vec![1, 2],
+///     vec![-1, -2],
+///     vec![3, 4],
+///     vec![-3, -4],
+///     vec![5, 6],
+/// ], rows.to_vecs());
+///
+/// let cols = Interleaved::cols(&[[1, 2], [3, 4]], &[[0], [0]]);
+/// assert_eq!(vec![vec![1, 0, 2], vec![3, 0, 4]], cols.to_vecs());
+/// ```
+#[derive(Debug)]
+pub struct Interleaved<'a, A: MatrixExt, B: MatrixExt<Element = A::Element>> {
+    a: &'a A,
+    b: &'a B,
+    by_rows: bool,
+}
+
+impl<'a, A, B> Interleaved<'a, A, B>
+where
+    A: MatrixExt,
+    B: MatrixExt<Element = A::Element>
+{
+    /// Alternates the rows of `a` and `b`.
+    ///
+    /// # Panics
+    /// Panics if both matrices do not have the same number of columns.
+    pub fn rows(a: &'a A, b: &'a B) -> Self {
+        if a.num_cols() != b.num_cols() {
+            panic!("Cannot interleave rows of a {:?} matrix with rows of a {:?} matrix.", a.shape(), b.shape())
+        }
+        Self { a, b, by_rows: true }
+    }
+
+    /// Alternates the columns of `a` and `b`.
+    ///
+    /// # Panics
+    /// Panics if both matrices do not have the same number of rows.
+    pub fn cols(a: &'a A, b: &'a B) -> Self {
+        if a.num_rows() != b.num_rows() {
+            panic!("Cannot interleave columns of a {:?} matrix with columns of a {:?} matrix.", a.shape(), b.shape())
+        }
+        Self { a, b, by_rows: false }
+    }
+
+    /// Returns the interleaved matrices.
+    #[inline]
+    pub fn inner(&self) -> (&'a A, &'a B) { (self.a, self.b) }
+
+    /// Finds which matrix (`true` for `a`) and which of its lines holds the `k`-th line,
+    /// `a` and `b` having respectively `len_a` and `len_b` lines.
+    #[inline]
+    fn locate(k: usize, len_a: usize, len_b: usize) -> (bool, usize) {
+        let common = len_a.min(len_b);
+        if k < 2 * common {
+            (k.is_multiple_of(2), k / 2)
+        }
+        else {
+            (len_a > len_b, k - common)
+        }
+    }
+}
+
+impl<'a, A, B> Clone for Interleaved<'a, A, B>
+where
+    A: MatrixExt,
+    B: MatrixExt<Element = A::Element>
+{
+    #[inline]
+    fn clone(&self) -> Self { *self }
+}
+
+impl<'a, A, B> Copy for Interleaved<'a, A, B>
+where
+    A: MatrixExt,
+    B: MatrixExt<Element = A::Element>
+{}
+
+impl<'a, A, B> MatrixExt for Interleaved<'a, A, B>
+where
+    A: MatrixExt,
+    B: MatrixExt<Element = A::Element>
+{
+    type Element = A::Element;
+
+    #[inline]
+    fn num_rows(&self) -> usize {
+        if self.by_rows { self.a.num_rows() + self.b.num_rows() } else { self.a.num_rows() }
+    }
+
+    #[inline]
+    fn num_cols(&self) -> usize {
+        if self.by_rows { self.a.num_cols() } else { self.a.num_cols() + self.b.num_cols() }
+    }
+
+    fn get(&self, row: usize, column: usize) -> Option<&Self::Element> {
+        if self.by_rows {
+            match Self::locate(row, self.a.num_rows(), self.b.num_rows()) {
+                (true, i) => self.a.get(i, column),
+                (false, i) => self.b.get(i, column),
+            }
+        }
+        else {
+            match Self::locate(column, self.a.num_cols(), self.b.num_cols()) {
+                (true, j) => self.a.get(row, j),
+                (false, j) => self.b.get(row, j),
+            }
+        }
+    }
+}
+
+
+/// A wrapper around a matrix recording which elements were modified since the last call to
+/// [`clear_dirty`](Tracked::clear_dirty), so that only the changed regions need to be processed.
+///
+/// Every element handed out mutably (by `get_mut` and all the methods built on it) is considered modified,
+/// even if its value does not actually change.
+///
+/// # Example
+/// ```rust
+/// use matrixable::prelude::*;
+/// use matrixable::wrappers::Tracked;
+///
+/// let mut grid = Tracked::new([['.'; 4]; 3]);
+///
+/// grid.set((0, 1), '#').unwrap();
+/// grid.set((2, 3), '#').unwrap();
+///
+/// assert!(grid.is_dirty(2, 3));
+/// assert!(!grid.is_dirty(1, 1));
+/// assert_eq!(vec![(0, 1), (2, 3)], grid.dirty_cells().collect::<Vec<_>>());
+/// assert_eq!(vec![0, 2], grid.dirty_rows().collect::<Vec<_>>());
+///
+/// grid.clear_dirty();
+/// assert!(!grid.any_dirty());
+///
+/// // Reading does not make elements dirty.
+/// assert_eq!(Some(&'#'), grid.get(0, 1));
+/// assert!(!grid.any_dirty());
+///
+/// assert_eq!([['.', '#', '.', '.'], ['.'; 4], ['.', '.', '.', '#']], grid.into_inner());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Tracked<M: MatrixMutExt> {
+    matrix: M,
+    // One flag per element, in Row Major Order.
+    dirty: Vec<bool>,
+}
+
+impl<M: MatrixMutExt> Tracked<M> {
+    /// Wraps a matrix, with no element marked as modified.
+    pub fn new(matrix: M) -> Self {
+        let dirty = alloc::vec![false; matrix.size()];
+        Self { matrix, dirty }
+    }
+
+    /// Returns the wrapped matrix.
+    #[inline]
+    pub fn inner(&self) -> &M { &self.matrix }
+
+    /// Consumes the wrapper and returns the wrapped matrix.
+    #[inline]
+    pub fn into_inner(self) -> M { self.matrix }
+
+    /// Checks if the element at the given subscripts was modified since the last call to [`clear_dirty`](Tracked::clear_dirty).
+    ///
+    /// Returns `false` if the subscripts are out of bounds.
+    #[inline]
+    pub fn is_dirty(&self, i: usize, j: usize) -> bool {
+        self.matrix.check(i, j) && self.dirty[self.matrix.index_from((i, j))]
+    }
+
+    /// Checks if any element was modified since the last call to [`clear_dirty`](Tracked::clear_dirty).
+    #[inline]
+    pub fn any_dirty(&self) -> bool {
+        self.dirty.contains(&true)
+    }
+
+    /// Returns an iterator over the subscripts of the modified elements, in *Row Major Order*.
+    pub fn dirty_cells(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.dirty
+            .iter()
+            .enumerate()
+            .filter(|(_, &d)| d)
+            .map(|(n, _)| self.matrix.subscripts_from(n))
+    }
+
+    /// Returns an iterator over the indexes of the rows containing at least one modified element.
+    pub fn dirty_rows(&self) -> impl Iterator<Item = usize> + '_ {
+        let cols = self.matrix.num_cols();
+        (0..self.matrix.num_rows()).filter(move |&i| self.dirty[i * cols..(i + 1) * cols].contains(&true))
+    }
+
+    /// Marks all the elements as unmodified.
+    #[inline]
+    pub fn clear_dirty(&mut self) {
+        self.dirty.fill(false);
+    }
+}
+
+impl<M: MatrixMutExt> MatrixExt for Tracked<M> {
+    type Element = M::Element;
+
+    #[inline]
+    fn num_rows(&self) -> usize { self.matrix.num_rows() }
+
+    #[inline]
+    fn num_cols(&self) -> usize { self.matrix.num_cols() }
+
+    #[inline]
+    fn get(&self, row: usize, column: usize) -> Option<&Self::Element> {
+        self.matrix.get(row, column)
+    }
+}
+
+impl<M: MatrixMutExt> MatrixMutExt for Tracked<M> {
+    #[inline]
+    fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut Self::Element> {
+        let n = row * self.matrix.num_cols() + column;
+        let x = self.matrix.get_mut(row, column)?;
+        self.dirty[n] = true;
+        Some(x)
+    }
+}
+
+
+/// An edit of a [`Journaled`] matrix: the subscripts of the element, its old value and its new value.
+pub type Edit<T> = ((usize, usize), T, T);
+
+/// A wrapper around a matrix logging every edit, so that they can be undone and redone.
+///
+/// Edits are grouped into steps by [`checkpoint`](Journaled::checkpoint): [`undo`](Journaled::undo)
+/// and [`redo`](Journaled::redo) work on whole steps. Making a new edit after undoing discards the steps
+/// which could have been redone.
+///
+/// To keep the journal complete, elements can only be modified through [`set`](Journaled::set), so this
+/// wrapper does not implement `MatrixMutExt`.
+///
+/// # Example
+/// ```rust
+/// use matrixable::MatrixExt;
+/// use matrixable::wrappers::Journaled;
+///
+/// let mut m = Journaled::new([[0; 3]; 2]);
+///
+/// m.set((0, 0), 1).unwrap();
+/// m.set((0, 1), 2).unwrap();
+/// m.checkpoint();
+/// m.set((1, 2), 3).unwrap();
+///
+/// assert!(m.undo());
+/// assert_eq!(&[[1, 2, 0], [0, 0, 0]], m.inner());
+///
+/// assert!(m.undo());
+/// assert_eq!(&[[0; 3]; 2], m.inner());
+/// assert!(!m.undo());
+///
+/// assert!(m.redo());
+/// assert_eq!(Some(&2), m.get(0, 1));
+///
+/// // A new edit discards what could have been redone.
+/// m.set((1, 0), 4).unwrap();
+/// assert!(!m.redo());
+/// assert_eq!(&[((1, 0), 0, 4)], m.pending());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Journaled<M: MatrixMutExt> {
+    matrix: M,
+    /// Steps which can be undone, the last one being the most recent.
+    done: Vec<Vec<Edit<M::Element>>>,
+    /// Edits made since the last checkpoint.
+    current: Vec<Edit<M::Element>>,
+    /// Steps which can be redone, the last one being the most recently undone.
+    undone: Vec<Vec<Edit<M::Element>>>,
+}
+
+impl<M: MatrixMutExt> Journaled<M>
+where M::Element: Clone
+{
+    /// Wraps a matrix, with an empty journal.
+    pub fn new(matrix: M) -> Self {
+        Self { matrix, done: Vec::new(), current: Vec::new(), undone: Vec::new() }
+    }
+
+    /// Returns the wrapped matrix.
+    #[inline]
+    pub fn inner(&self) -> &M { &self.matrix }
+
+    /// Consumes the wrapper and returns the wrapped matrix.
+    #[inline]
+    pub fn into_inner(self) -> M { self.matrix }
+
+    /// Changes the value of an element at the intersection of the `i`-th row and the `j`-th column of the matrix,
+    /// and logs the edit.
+    ///
+    /// # Error
+    /// An error is returned if any of those indexes are out of bounds.
+    pub fn set(&mut self, subscripts: (usize, usize), val: M::Element) -> Result<(), &'static str> {
+        match self.matrix.get_mut(subscripts.0, subscripts.1) {
+            Some(x) => {
+                let old = ::core::mem::replace(x, val.clone());
+                self.current.push((subscripts, old, val));
+                self.undone.clear();
+                Ok(())
+            }
+            None => Err("Cannot access element from indexes."),
+        }
+    }
+
+    /// Ends the current step: the edits made since the previous checkpoint will be undone together.
+    #[inline]
+    pub fn checkpoint(&mut self) {
+        if !self.current.is_empty() {
+            self.done.push(::core::mem::take(&mut self.current));
+        }
+    }
+
+    /// Returns the edits made since the last checkpoint, in order.
+    #[inline]
+    pub fn pending(&self) -> &[Edit<M::Element>] { &self.current }
+
+    /// Checks if there is a step to undo.
+    #[inline]
+    pub fn can_undo(&self) -> bool { !self.current.is_empty() || !self.done.is_empty() }
+
+    /// Checks if there is a step to redo.
+    #[inline]
+    pub fn can_redo(&self) -> bool { !self.undone.is_empty() }
+
+    /// Reverts the most recent step (ending the current one first).
+    ///
+    /// Returns `false` if there was nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        self.checkpoint();
+        match self.done.pop() {
+            Some(step) => {
+                for (pos, old, _) in step.iter().rev() {
+                    *self.matrix.get_mut(pos.0, pos.1).unwrap() = old.clone();
+                }
+                self.undone.push(step);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Applies again the most recently undone step.
+    ///
+    /// Returns `false` if there was nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        match self.undone.pop() {
+            Some(step) => {
+                for (pos, _, new) in step.iter() {
+                    *self.matrix.get_mut(pos.0, pos.1).unwrap() = new.clone();
+                }
+                self.done.push(step);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Forgets all the edits, keeping the matrix as it is.
+    pub fn clear_history(&mut self) {
+        self.done.clear();
+        self.current.clear();
+        self.undone.clear();
+    }
+}
+
+impl<M: MatrixMutExt> MatrixExt for Journaled<M> {
+    type Element = M::Element;
+
+    #[inline]
+    fn num_rows(&self) -> usize { self.matrix.num_rows() }
+
+    #[inline]
+    fn num_cols(&self) -> usize { self.matrix.num_cols() }
+
+    #[inline]
+    fn get(&self, row: usize, column: usize) -> Option<&Self::Element> {
+        self.matrix.get(row, column)
+    }
+}
+
+
+/// A function called by [`Hooked`] with the subscripts and the new value of a written element.
+type Hook<'h, T> = Box<dyn FnMut(usize, usize, &T) + 'h>;
+
+/// A wrapper around a matrix calling functions registered with [`on_set`](Hooked::on_set) whenever an element is written.
+///
+/// As a `MatrixMutExt` hands out mutable references, the new value of an element is only known once the reference
+/// is released. Hooks are therefore called for each element obtained mutably when calling [`flush`](Hooked::flush)
+/// (also done by [`inner`](Hooked::inner) and [`into_inner`](Hooked::into_inner)) or when the wrapper is dropped,
+/// with the value the element has then.
+///
+/// # Example
+/// ```rust
+/// use std::cell::RefCell;
+/// use matrixable::prelude::*;
+/// use matrixable::wrappers::Hooked;
+///
+/// let log = RefCell::new(Vec::new());
+///
+/// let mut m = Hooked::new([[0; 3]; 2]);
+/// m.on_set(|i, j, x| log.borrow_mut().push((i, j, *x)));
+///
+/// m.set((0, 1), 5).unwrap();
+/// m.flush();
+/// assert_eq!(vec![(0, 1, 5)], *log.borrow());
+///
+/// *m.get_mut(1, 2).unwrap() += 7;
+/// m.swap((0, 1), (1, 0));
+/// m.flush();
+/// assert_eq!(vec![(0, 1, 5), (1, 2, 7), (0, 1, 0), (1, 0, 5)], *log.borrow());
+///
+/// // Reading does not call the hooks.
+/// assert_eq!(Some(&7), m.get(1, 2));
+/// assert_eq!(4, log.borrow().len());
+/// ```
+pub struct Hooked<'h, M: MatrixMutExt> {
+    matrix: M,
+    hooks: RefCell<Vec<Hook<'h, M::Element>>>,
+    /// Subscripts of the elements obtained mutably since the hooks were last called.
+    pending: RefCell<Vec<(usize, usize)>>,
+}
+
+impl<'h, M: MatrixMutExt> Hooked<'h, M> {
+    /// Wraps a matrix, with no hook registered.
+    pub fn new(matrix: M) -> Self {
+        Self { matrix, hooks: RefCell::new(Vec::new()), pending: RefCell::new(Vec::new()) }
+    }
+
+    /// Registers a function called with the subscripts and the new value of each written element.
+    pub fn on_set(&mut self, f: impl FnMut(usize, usize, &M::Element) + 'h) -> &mut Self {
+        self.flush();
+        self.hooks.get_mut().push(Box::new(f));
+        self
+    }
+
+    /// Calls the hooks for the elements obtained mutably since the hooks were last called.
+    pub fn flush(&self) {
+        let pending = ::core::mem::take(&mut *self.pending.borrow_mut());
+        if pending.is_empty() {
+            return
+        }
+        let mut hooks = self.hooks.borrow_mut();
+        for (i, j) in pending {
+            let x = self.matrix.get(i, j).unwrap();
+            for hook in hooks.iter_mut() {
+                hook(i, j, x);
+            }
+        }
+    }
+
+    /// Returns the wrapped matrix.
+    #[inline]
+    pub fn inner(&self) -> &M {
+        self.flush();
+        &self.matrix
+    }
+
+    /// Calls the pending hooks, then consumes the wrapper and returns the wrapped matrix.
+    pub fn into_inner(self) -> M {
+        self.flush();
+        let this = ::core::mem::ManuallyDrop::new(self);
+        // SAFETY: `this` is never dropped nor used again, so each field is moved out exactly once.
+        unsafe {
+            drop(::core::ptr::read(&this.hooks));
+            drop(::core::ptr::read(&this.pending));
+            ::core::ptr::read(&this.matrix)
+        }
+    }
+}
+
+impl<'h, M: MatrixMutExt> Drop for Hooked<'h, M> {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+impl<'h, M: MatrixMutExt + fmt::Debug> fmt::Debug for Hooked<'h, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Hooked")
+            .field("matrix", &self.matrix)
+            .field("hooks", &self.hooks.borrow().len())
+            .field("pending", &self.pending.borrow())
+            .finish()
+    }
+}
+
+impl<'h, M: MatrixMutExt> MatrixExt for Hooked<'h, M> {
+    type Element = M::Element;
+
+    #[inline]
+    fn num_rows(&self) -> usize { self.matrix.num_rows() }
+
+    #[inline]
+    fn num_cols(&self) -> usize { self.matrix.num_cols() }
+
+    #[inline]
+    fn get(&self, row: usize, column: usize) -> Option<&Self::Element> {
+        self.matrix.get(row, column)
+    }
+}
+
+impl<'h, M: MatrixMutExt> MatrixMutExt for Hooked<'h, M> {
+    #[inline]
+    fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut Self::Element> {
+        let x = self.matrix.get_mut(row, column)?;
+        self.pending.get_mut().push((row, column));
+        Some(x)
+    }
+}
+
+
+/// A matrix shared between threads behind a [`RwLock`]: any number of readers or one writer at a time.
+///
+/// The guards returned by [`read`](SyncMatrix::read) and [`write`](SyncMatrix::write) implement `MatrixExt`
+/// (and `MatrixMutExt` for writing), so they can be used as the matrix itself.
+/// A panic while holding the lock does not prevent further access to the matrix.
+///
+/// For concurrent writers to disjoint rows, see [`SyncRows`].
+///
+/// # Example
+/// ```rust
+/// use matrixable::prelude::*;
+/// use matrixable::wrappers::SyncMatrix;
+///
+/// let m = SyncMatrix::new([[0; 3]; 2]);
+///
+/// std::thread::scope(|s| {
+///     for k in 0..6 {
+///         let m = &m;
+///         s.spawn(move || m.write().set_nth(k, k * 10).unwrap());
+///     }
+/// });
+///
+/// let guard = m.read();
+/// assert_eq!(Some(&50), guard.get(1, 2));
+/// assert_eq!(vec![vec![0, 10, 20], vec![30, 40, 50]], guard.to_vecs());
+/// ```
+#[derive(Debug, Default)]
+pub struct SyncMatrix<M> {
+    lock: RwLock<M>,
+}
+
+impl<M> SyncMatrix<M> {
+    /// Wraps a matrix.
+    #[inline]
+    pub fn new(matrix: M) -> Self {
+        Self { lock: RwLock::new(matrix) }
+    }
+
+    /// Locks the matrix for reading, blocking the current thread while it is locked for writing.
+    #[inline]
+    pub fn read(&self) -> RwLockReadGuard<'_, M> {
+        self.lock.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Locks the matrix for writing, blocking the current thread while it is locked.
+    #[inline]
+    pub fn write(&self) -> RwLockWriteGuard<'_, M> {
+        self.lock.write().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns the matrix mutably, without locking since the wrapper is borrowed mutably.
+    #[inline]
+    pub fn inner_mut(&mut self) -> &mut M {
+        self.lock.get_mut().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Consumes the wrapper and returns the matrix.
+    #[inline]
+    pub fn into_inner(self) -> M {
+        self.lock.into_inner().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<'a, M: MatrixExt> MatrixExt for RwLockReadGuard<'a, M> {
+    type Element = M::Element;
+
+    #[inline]
+    fn num_rows(&self) -> usize { (**self).num_rows() }
+
+    #[inline]
+    fn num_cols(&self) -> usize { (**self).num_cols() }
+
+    #[inline]
+    fn get(&self, row: usize, column: usize) -> Option<&Self::Element> {
+        (**self).get(row, column)
+    }
+}
+
+impl<'a, M: MatrixExt> MatrixExt for RwLockWriteGuard<'a, M> {
+    type Element = M::Element;
+
+    #[inline]
+    fn num_rows(&self) -> usize { (**self).num_rows() }
+
+    #[inline]
+    fn num_cols(&self) -> usize { (**self).num_cols() }
+
+    #[inline]
+    fn get(&self, row: usize, column: usize) -> Option<&Self::Element> {
+        (**self).get(row, column)
+    }
+}
+
+impl<'a, M: MatrixMutExt> MatrixMutExt for RwLockWriteGuard<'a, M> {
+    #[inline]
+    fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut Self::Element> {
+        (**self).get_mut(row, column)
+    }
+}
+
+
+/// A matrix shared between threads with one [`RwLock`] per row, so that threads writing to different rows
+/// do not wait for each other.
+///
+/// # Example
+/// ```rust
+/// use matrixable::wrappers::SyncRows;
+///
+/// let m = SyncRows::new(&[[0u64; 4]; 3]);
+///
+/// std::thread::scope(|s| {
+///     for i in 0..m.num_rows() {
+///         let m = &m;
+///         s.spawn(move || {
+///             let mut row = m.write_row(i).unwrap();
+///             for (j, x) in row.iter_mut().enumerate() {
+///                 *x = (10 * i + j) as u64;
+///             }
+///         });
+///     }
+/// });
+///
+/// assert_eq!(&[20, 21, 22, 23][..], &m.read_row(2).unwrap()[..]);
+/// assert!(m.read_row(3).is_none());
+///
+/// let snapshot: [[u64; 4]; 3] = m.to_matrix();
+/// assert_eq!([[0, 1, 2, 3], [10, 11, 12, 13], [20, 21, 22, 23]], snapshot);
+/// ```
+#[derive(Debug, Default)]
+pub struct SyncRows<T> {
+    rows: Vec<RwLock<Vec<T>>>,
+    cols: usize,
+}
+
+impl<T> SyncRows<T> {
+    /// Clones the rows of a matrix, each one behind its own lock.
+    pub fn new<M: MatrixExt<Element = T>>(m: &M) -> Self
+    where T: Clone
+    {
+        let rows = m.rows().map(|row| RwLock::new(row.cloned().collect())).collect();
+        Self { rows, cols: m.num_cols() }
+    }
+
+    /// Returns the number of rows.
+    #[inline]
+    pub fn num_rows(&self) -> usize { self.rows.len() }
+
+    /// Returns the number of columns.
+    #[inline]
+    pub fn num_cols(&self) -> usize { self.cols }
+
+    /// Locks the `i`-th row for reading, or returns `None` if it does not exist.
+    #[inline]
+    pub fn read_row(&self, i: usize) -> Option<RwLockReadGuard<'_, Vec<T>>> {
+        Some(self.rows.get(i)?.read().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    /// Locks the `i`-th row for writing, or returns `None` if it does not exist.
+    ///
+    /// The guard only gives a slice, so elements can be modified but the length of the row cannot change.
+    #[inline]
+    pub fn write_row(&self, i: usize) -> Option<RowWriteGuard<'_, T>> {
+        Some(RowWriteGuard(self.rows.get(i)?.write().unwrap_or_else(PoisonError::into_inner)))
+    }
+
+    /// Clones the elements into a new matrix, locking one row at a time.
+    pub fn to_matrix<O>(&self) -> O
+    where
+        T: Clone,
+        O: MatrixExtFromIter<T>
+    {
+        let mut v = Vec::with_capacity(self.rows.len() * self.cols);
+        for i in 0..self.rows.len() {
+            v.extend(self.read_row(i).unwrap().iter().cloned());
+        }
+        O::from_iter(v, self.cols)
+    }
+}
+
+/// A row of a [`SyncRows`] locked for writing, created by [`SyncRows::write_row`].
+///
+/// It dereferences to a slice of the elements of the row, and releases the lock when dropped.
+#[derive(Debug)]
+pub struct RowWriteGuard<'a, T>(RwLockWriteGuard<'a, Vec<T>>);
+
+impl<T> Deref for RowWriteGuard<'_, T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] { &self.0 }
+}
+
+impl<T> DerefMut for RowWriteGuard<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T] { &mut self.0 }
+}